@@ -1,6 +1,6 @@
 //! Account service example demonstrating user authentication
 
-use appwrite::{Account, Client, OAuthProvider};
+use appwrite::{Account, Client, OAuthProvider, ID};
 use std::env;
 
 #[tokio::main]
@@ -18,12 +18,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Example 1: Create a new user account
     println!("\n📝 Creating a new user account...");
     match account.create(
-        "unique_user_123",
+        &ID::unique(),
         "user@example.com",
         "securepassword123",
         Some("John Doe"),
     ).await {
-        Ok(user) => println!("✅ User created successfully: {}", user),
+        Ok(user) => println!("✅ User created successfully: {:?}", user),
         Err(e) => println!("❌ Failed to create user: {}", e),
     }
 
@@ -31,26 +31,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n🔐 Creating email/password session...");
     match account.create_email_password_session("user@example.com", "securepassword123").await {
         Ok(session) => {
-            println!("✅ Session created successfully: {}", session);
+            println!("✅ Session created successfully: {:?}", session);
             
             // Now that we're logged in, we can get user info
             println!("\n👤 Getting current user info...");
             match account.get().await {
-                Ok(user) => println!("✅ Current user: {}", user),
+                Ok(user) => println!("✅ Current user: {:?}", user),
                 Err(e) => println!("❌ Failed to get user: {}", e),
             }
 
             // Example 3: Update user name
             println!("\n✏️ Updating user name...");
             match account.update_name("Jane Doe").await {
-                Ok(user) => println!("✅ Name updated successfully: {}", user),
+                Ok(user) => println!("✅ Name updated successfully: {:?}", user),
                 Err(e) => println!("❌ Failed to update name: {}", e),
             }
 
             // Example 4: Get user preferences
             println!("\n⚙️ Getting user preferences...");
             match account.get_prefs().await {
-                Ok(prefs) => println!("✅ User preferences: {}", prefs),
+                Ok(prefs) => println!("✅ User preferences: {:?}", prefs),
                 Err(e) => println!("❌ Failed to get preferences: {}", e),
             }
 
@@ -62,21 +62,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 "notifications": true
             });
             match account.update_prefs(new_prefs).await {
-                Ok(prefs) => println!("✅ Preferences updated: {}", prefs),
+                Ok(prefs) => println!("✅ Preferences updated: {:?}", prefs),
                 Err(e) => println!("❌ Failed to update preferences: {}", e),
             }
 
             // Example 6: List all sessions
             println!("\n📋 Listing all sessions...");
             match account.list_sessions().await {
-                Ok(sessions) => println!("✅ User sessions: {}", sessions),
+                Ok(sessions) => println!("✅ User sessions: {:?}", sessions),
                 Err(e) => println!("❌ Failed to list sessions: {}", e),
             }
 
             // Example 7: Create email verification
             println!("\n📧 Creating email verification...");
             match account.create_verification("https://example.com/verify").await {
-                Ok(verification) => println!("✅ Verification created: {}", verification),
+                Ok(verification) => println!("✅ Verification created: {:?}", verification),
                 Err(e) => println!("❌ Failed to create verification: {}", e),
             }
         }
@@ -86,7 +86,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Example 8: Create anonymous session
     println!("\n👤 Creating anonymous session...");
     match account.create_anonymous_session().await {
-        Ok(session) => println!("✅ Anonymous session created: {}", session),
+        Ok(session) => println!("✅ Anonymous session created: {:?}", session),
         Err(e) => println!("❌ Failed to create anonymous session: {}", e),
     }
 
@@ -105,7 +105,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Example 10: Password recovery
     println!("\n🔄 Creating password recovery...");
     match account.create_recovery("user@example.com", "https://example.com/recovery").await {
-        Ok(recovery) => println!("✅ Recovery created: {}", recovery),
+        Ok(recovery) => println!("✅ Recovery created: {:?}", recovery),
         Err(e) => println!("❌ Failed to create recovery: {}", e),
     }
 
@@ -114,6 +114,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 // Helper function to demonstrate error handling
+#[allow(dead_code)]
 async fn safe_account_operation<F, Fut>(operation_name: &str, operation: F)
 where
     F: FnOnce() -> Fut,
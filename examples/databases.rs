@@ -1,8 +1,17 @@
 //! Database service example demonstrating CRUD operations
 
 use appwrite::{Client, Databases, Query, Permission, Role};
+use serde::{Deserialize, Serialize};
 use std::env;
 
+/// Attributes of the documents in the example collection
+#[derive(Debug, Serialize, Deserialize)]
+struct Person {
+    name: String,
+    email: String,
+    age: i64,
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize Appwrite client
@@ -19,7 +28,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Example 1: List all databases
     println!("\n📋 Listing all databases...");
     match databases.list(None, None).await {
-        Ok(db_list) => println!("✅ Databases: {}", db_list),
+        Ok(db_list) => println!("✅ Databases: {:?}", db_list),
         Err(e) => println!("❌ Failed to list databases: {}", e),
     }
 
@@ -28,7 +37,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let database_id = "example_db_123";
     match databases.create(database_id, "Example Database", Some(true)).await {
         Ok(database) => {
-            println!("✅ Database created: {}", database);
+            println!("✅ Database created: {:?}", database);
 
             // Example 3: Create a collection in the database
             println!("\n📂 Creating a collection...");
@@ -47,7 +56,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Some(true),  // enabled
             ).await {
                 Ok(collection) => {
-                    println!("✅ Collection created: {}", collection);
+                    println!("✅ Collection created: {:?}", collection);
 
                     // Example 4: Create attributes for the collection
                     println!("\n🏷️ Creating string attribute...");
@@ -61,7 +70,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Some(false), // array
                         Some(false), // encrypt
                     ).await {
-                        Ok(attr) => println!("✅ String attribute created: {}", attr),
+                        Ok(attr) => println!("✅ String attribute created: {:?}", attr),
                         Err(e) => println!("❌ Failed to create string attribute: {}", e),
                     }
 
@@ -74,7 +83,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         None,    // default
                         Some(false), // array
                     ).await {
-                        Ok(attr) => println!("✅ Email attribute created: {}", attr),
+                        Ok(attr) => println!("✅ Email attribute created: {:?}", attr),
                         Err(e) => println!("❌ Failed to create email attribute: {}", e),
                     }
 
@@ -89,7 +98,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Some(18), // default
                         Some(false), // array
                     ).await {
-                        Ok(attr) => println!("✅ Integer attribute created: {}", attr),
+                        Ok(attr) => println!("✅ Integer attribute created: {:?}", attr),
                         Err(e) => println!("❌ Failed to create integer attribute: {}", e),
                     }
 
@@ -98,17 +107,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                     // Example 5: Create a document
                     println!("\n📄 Creating a document...");
-                    let document_data = serde_json::json!({
-                        "name": "John Doe",
-                        "email": "john@example.com",
-                        "age": 30
-                    });
+                    let person = Person {
+                        name: "John Doe".to_string(),
+                        email: "john@example.com".to_string(),
+                        age: 30,
+                    };
 
                     match databases.create_document(
                         database_id,
                         collection_id,
                         "user_doc_123",
-                        document_data,
+                        &person,
                         Some(vec![
                             Permission::read(Role::any()),
                             Permission::update(Role::user("user_123")),
@@ -116,12 +125,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         ]),
                     ).await {
                         Ok(document) => {
-                            println!("✅ Document created: {}", document);
+                            println!("✅ Document created: {:?}", document);
 
                             // Example 6: Get the document
                             println!("\n📖 Getting the document...");
-                            match databases.get_document(database_id, collection_id, "user_doc_123", None).await {
-                                Ok(doc) => println!("✅ Retrieved document: {}", doc),
+                            match databases.get_document::<Person>(database_id, collection_id, "user_doc_123", None).await {
+                                Ok(doc) => println!("✅ Retrieved document: {} ({})", doc.data.name, doc.data.email),
                                 Err(e) => println!("❌ Failed to get document: {}", e),
                             }
 
@@ -132,14 +141,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 "age": 31
                             });
 
-                            match databases.update_document(
+                            match databases.update_document::<Person, _>(
                                 database_id,
                                 collection_id,
                                 "user_doc_123",
-                                Some(update_data),
+                                Some(&update_data),
                                 None, // permissions
                             ).await {
-                                Ok(doc) => println!("✅ Document updated: {}", doc),
+                                Ok(doc) => println!("✅ Document updated: {:?}", doc),
                                 Err(e) => println!("❌ Failed to update document: {}", e),
                             }
 
@@ -152,12 +161,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 Query::order_desc("$createdAt"),
                             ];
 
-                            match databases.list_documents(
+                            match databases.list_documents::<Person>(
                                 database_id,
                                 collection_id,
                                 Some(queries),
                             ).await {
-                                Ok(docs) => println!("✅ Documents found: {}", docs),
+                                Ok(docs) => println!("✅ Documents found: {:?}", docs),
                                 Err(e) => println!("❌ Failed to list documents: {}", e),
                             }
 
@@ -168,12 +177,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 Query::limit(5),
                             ];
 
-                            match databases.list_documents(
+                            match databases.list_documents::<Person>(
                                 database_id,
                                 collection_id,
                                 Some(search_queries),
                             ).await {
-                                Ok(docs) => println!("✅ Search results: {}", docs),
+                                Ok(docs) => println!("✅ Search results: {:?}", docs),
                                 Err(e) => println!("❌ Failed to search documents: {}", e),
                             }
 
@@ -187,7 +196,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 vec!["email".to_string()],
                                 None, // orders
                             ).await {
-                                Ok(index) => println!("✅ Index created: {}", index),
+                                Ok(index) => println!("✅ Index created: {:?}", index),
                                 Err(e) => println!("❌ Failed to create index: {}", e),
                             }
                         }
@@ -202,7 +211,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 11: Demonstrate complex queries
     println!("\n🔍 Complex query examples...");
-    let complex_queries = vec![
+    let complex_queries = [
         Query::and_queries(vec![
             Query::greater_than("age", 18),
             Query::less_than("age", 65),
@@ -228,10 +237,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 // Helper to create a test database
+#[allow(dead_code)]
 async fn ensure_test_database(
     databases: &Databases<'_>,
     database_id: &str,
-) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+) -> Result<appwrite::models::Database, Box<dyn std::error::Error>> {
     // Try to get the database first
     match databases.get(database_id).await {
         Ok(db) => Ok(db),
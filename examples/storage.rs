@@ -20,7 +20,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Example 1: List all buckets
     println!("\n📋 Listing all storage buckets...");
     match storage.list_buckets(None, None).await {
-        Ok(buckets) => println!("✅ Buckets: {}", buckets),
+        Ok(buckets) => println!("✅ Buckets: {:?}", buckets),
         Err(e) => println!("❌ Failed to list buckets: {}", e),
     }
 
@@ -45,7 +45,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Some(false), // antivirus
     ).await {
         Ok(bucket) => {
-            println!("✅ Bucket created: {}", bucket);
+            println!("✅ Bucket created: {:?}", bucket);
 
             // Example 3: Create a test file to upload
             println!("\n📝 Creating a test file...");
@@ -72,24 +72,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 ]),
             ).await {
                 Ok(file) => {
-                    println!("✅ File uploaded from path: {}", file);
+                    println!("✅ File uploaded from path: {:?}", file);
 
                     // Example 5: Get file details
                     println!("\n📖 Getting file details...");
                     match storage.get_file(bucket_id, "test_file_123").await {
-                        Ok(file_info) => println!("✅ File details: {}", file_info),
+                        Ok(file_info) => println!("✅ File details: {:?}", file_info),
                         Err(e) => println!("❌ Failed to get file: {}", e),
                     }
 
-                    // Example 6: Get file for download
-                    println!("\n⬇️ Getting file download URL...");
+                    // Example 6: Download the file
+                    println!("\n⬇️ Downloading file...");
                     match storage.get_file_download(bucket_id, "test_file_123").await {
-                        Ok(download_url) => println!("✅ Download URL: {}", download_url),
-                        Err(e) => println!("❌ Failed to get download URL: {}", e),
+                        Ok(contents) => println!("✅ Downloaded {} bytes: {}", contents.len(), String::from_utf8_lossy(&contents)),
+                        Err(e) => println!("❌ Failed to download file: {}", e),
                     }
 
                     // Example 7: Get file preview (for supported formats)
-                    println!("\n👁️ Getting file preview URL...");
+                    println!("\n👁️ Getting file preview...");
                     match storage.get_file_preview(
                         bucket_id,
                         "test_file_123",
@@ -105,8 +105,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         None,     // background
                         Some(ImageFormat::Png),
                     ).await {
-                        Ok(preview_url) => println!("✅ Preview URL: {}", preview_url),
-                        Err(e) => println!("❌ Failed to get preview URL: {}", e),
+                        Ok(preview) => println!("✅ Preview image: {} bytes", preview.len()),
+                        Err(e) => println!("❌ Failed to get preview: {}", e),
                     }
 
                     // Example 8: Update file
@@ -121,7 +121,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             Permission::delete(Role::user("user_123")),
                         ]),
                     ).await {
-                        Ok(updated_file) => println!("✅ File updated: {}", updated_file),
+                        Ok(updated_file) => println!("✅ File updated: {:?}", updated_file),
                         Err(e) => println!("❌ Failed to update file: {}", e),
                     }
                 }
@@ -143,7 +143,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 input_file_bytes,
                 Some(vec![Permission::read(Role::any())]),
             ).await {
-                Ok(file) => println!("✅ File uploaded from bytes: {}", file),
+                Ok(file) => println!("✅ File uploaded from bytes: {:?}", file),
                 Err(e) => println!("❌ Failed to upload file from bytes: {}", e),
             }
 
@@ -154,14 +154,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 None, // queries
                 None, // search
             ).await {
-                Ok(files) => println!("✅ Files in bucket: {}", files),
+                Ok(files) => println!("✅ Files in bucket: {:?}", files),
                 Err(e) => println!("❌ Failed to list files: {}", e),
             }
 
             // Example 11: Get bucket details
             println!("\n🪣 Getting bucket details...");
             match storage.get_bucket(bucket_id).await {
-                Ok(bucket_info) => println!("✅ Bucket details: {}", bucket_info),
+                Ok(bucket_info) => println!("✅ Bucket details: {:?}", bucket_info),
                 Err(e) => println!("❌ Failed to get bucket: {}", e),
             }
 
@@ -179,7 +179,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Some(false), // encryption
                 Some(true),  // antivirus
             ).await {
-                Ok(updated_bucket) => println!("✅ Bucket updated: {}", updated_bucket),
+                Ok(updated_bucket) => println!("✅ Bucket updated: {:?}", updated_bucket),
                 Err(e) => println!("❌ Failed to update bucket: {}", e),
             }
 
@@ -206,7 +206,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         large_file_input,
         Some(vec![Permission::read(Role::any())]),
     ).await {
-        Ok(file) => println!("✅ Large file uploaded: {}", file),
+        Ok(file) => println!("✅ Large file uploaded: {:?}", file),
         Err(e) => println!("❌ Failed to upload large file: {}", e),
     }
 
@@ -215,6 +215,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 // Helper function to create test image file
+#[allow(dead_code)]
 fn create_test_image() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
     // Create a simple test "image" (actually just some bytes with PNG header-like data)
     let mut image_data = vec![
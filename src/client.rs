@@ -1,12 +1,45 @@
 //! HTTP client for Appwrite API
 
 use crate::error::{AppwriteError, Result};
-// use crate::input_file::InputFile; // Currently unused
-use reqwest::{Client as ReqwestClient, Method, RequestBuilder, Response};
+use crate::input_file::InputFile;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::retry::RetryPolicy;
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+use reqwest::{Certificate, Client as ReqwestClient, Identity, Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
 use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
+/// Binary payload attached to a multipart request
+#[derive(Debug, Clone)]
+struct FilePart {
+    param_name: String,
+    filename: String,
+    mime_type: Option<String>,
+    data: Bytes,
+}
+
+/// Progress of a file upload, reported after each chunk
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadProgress {
+    /// ID of the file being uploaded
+    pub id: String,
+    /// Percentage of the file uploaded so far
+    pub progress: f64,
+    /// Number of bytes uploaded so far
+    pub size_uploaded: u64,
+    /// Total size of the file in bytes
+    pub size_total: u64,
+    /// Number of chunks the file is split into
+    pub chunks_total: u64,
+    /// Number of chunks uploaded so far
+    pub chunks_uploaded: u64,
+}
+
 /// HTTP client for communicating with Appwrite API
 #[derive(Debug, Clone)]
 pub struct Client {
@@ -15,6 +48,11 @@ pub struct Client {
     headers: HashMap<String, String>,
     chunk_size: usize,
     self_signed: bool,
+    root_certificates: Vec<Certificate>,
+    identity: Option<Identity>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
 }
 
 impl Default for Client {
@@ -50,6 +88,11 @@ impl Client {
             headers,
             chunk_size: 5 * 1024 * 1024, // 5MB
             self_signed: false,
+            root_certificates: Vec::new(),
+            identity: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            rate_limit: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -70,9 +113,63 @@ impl Client {
     }
 
     /// Set whether to allow self-signed certificates
-    pub fn set_self_signed(mut self, status: bool) -> Self {
+    ///
+    /// This disables TLS certificate verification entirely. Prefer
+    /// [`Client::add_root_certificate`] to trust a specific certificate
+    /// authority instead.
+    pub fn set_self_signed(mut self, status: bool) -> Result<Self> {
         self.self_signed = status;
-        self
+        self.http_client = self.build_http_client()?;
+        Ok(self)
+    }
+
+    /// Trust the certificate authorities in a PEM encoded bundle
+    ///
+    /// The certificates are trusted in addition to the system roots.
+    pub fn add_root_certificate(mut self, pem: impl AsRef<[u8]>) -> Result<Self> {
+        let certificates = Certificate::from_pem_bundle(pem.as_ref())
+            .map_err(|e| AppwriteError::invalid_parameter(format!("Invalid certificate: {}", e)))?;
+        if certificates.is_empty() {
+            return Err(AppwriteError::invalid_parameter(
+                "Invalid certificate: no PEM certificates found",
+            ));
+        }
+
+        self.root_certificates.extend(certificates);
+        self.http_client = self.build_http_client()?;
+        Ok(self)
+    }
+
+    /// Authenticate with a client certificate
+    ///
+    /// # Arguments
+    /// * `certificate` - PEM encoded certificate chain
+    /// * `key` - PEM encoded PKCS#8 private key
+    pub fn set_client_certificate(
+        mut self,
+        certificate: impl AsRef<[u8]>,
+        key: impl AsRef<[u8]>,
+    ) -> Result<Self> {
+        let identity = Identity::from_pkcs8_pem(certificate.as_ref(), key.as_ref())
+            .map_err(|e| AppwriteError::invalid_parameter(format!("Invalid client certificate: {}", e)))?;
+
+        self.identity = Some(identity);
+        self.http_client = self.build_http_client()?;
+        Ok(self)
+    }
+
+    /// Build the underlying HTTP client from the TLS settings
+    fn build_http_client(&self) -> Result<ReqwestClient> {
+        let mut builder = ReqwestClient::builder().danger_accept_invalid_certs(self.self_signed);
+
+        for certificate in &self.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(identity) = &self.identity {
+            builder = builder.identity(identity.clone());
+        }
+
+        Ok(builder.build()?)
     }
 
     /// Add a custom header
@@ -112,14 +209,313 @@ impl Client {
     }
 
     /// Make an HTTP request to the Appwrite API
+    ///
+    /// JSON bodies are parsed and any other body is returned as a string. Use
+    /// [`Client::call_bytes`] or [`Client::call_stream`] for binary responses.
     pub async fn call(
         &self,
         method: &str,
         path: &str,
         headers: Option<HashMap<String, String>>,
         params: Option<Map<String, Value>>,
-        response_type: Option<&str>,
     ) -> Result<Value> {
+        // Execute request
+        let response = self.send(method, path, headers, params, None, false).await?;
+        
+        self.handle_response(response).await
+    }
+
+    /// Make an HTTP request and deserialize the JSON response into `T`
+    pub async fn call_as<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        path: &str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<Map<String, Value>>,
+    ) -> Result<T> {
+        let value = self.call(method, path, headers, params).await?;
+
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Make an HTTP request and return the raw response body
+    ///
+    /// Used for endpoints that return file contents rather than JSON.
+    pub async fn call_bytes(
+        &self,
+        method: &str,
+        path: &str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<Map<String, Value>>,
+    ) -> Result<Bytes> {
+        let response = self.send(method, path, headers, params, None, false).await?;
+        let response = self.check_response(response).await?;
+
+        Ok(response.bytes().await?)
+    }
+
+    /// Make an HTTP request and stream the response body in chunks
+    ///
+    /// Errors returned by the server are reported before the stream is
+    /// produced, so the stream only yields transport errors.
+    pub async fn call_stream(
+        &self,
+        method: &str,
+        path: &str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<Map<String, Value>>,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let response = self.send(method, path, headers, params, None, false).await?;
+        let response = self.check_response(response).await?;
+
+        Ok(response.bytes_stream().map_err(AppwriteError::from))
+    }
+
+    /// Upload a file as a `multipart/form-data` request
+    ///
+    /// The contents of `file` are attached as a binary part named `param_name`,
+    /// alongside the remaining `params` which are sent as text fields. Files
+    /// larger than [`Client::chunk_size`] are sent as sequential chunks with
+    /// `content-range` headers. When `upload_id` refers to an upload that was
+    /// interrupted, the upload resumes after the last chunk acknowledged by
+    /// the server. An existing file with that ID but another name or size is
+    /// reported as a `409` conflict rather than resumed.
+    ///
+    /// Each chunk is retried on its own according to the client's
+    /// [`RetryPolicy`], so a transient failure does not restart the upload.
+    /// `on_progress` is called after every acknowledged chunk. Cancelling
+    /// `cancellation` aborts the upload, including a chunk that is in flight,
+    /// with [`AppwriteError::Cancelled`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn upload(
+        &self,
+        path: &str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<Map<String, Value>>,
+        param_name: &str,
+        file: &InputFile,
+        upload_id: Option<&str>,
+        mut on_progress: Option<&mut (dyn FnMut(UploadProgress) + Send)>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Value> {
+        let mut headers = headers.unwrap_or_default();
+        headers.insert("content-type".to_string(), "multipart/form-data".to_string());
+        let params = params.unwrap_or_default();
+
+        let size = file.size().await?;
+        let chunk_size = self.chunk_size.max(1) as u64;
+        let chunks_total = ((size + chunk_size - 1) / chunk_size).max(1);
+
+        let mut report = |result: &Value, size_uploaded: u64, chunks_uploaded: u64| {
+            if let Some(on_progress) = on_progress.as_mut() {
+                on_progress(UploadProgress {
+                    id: result
+                        .get("$id")
+                        .and_then(|id| id.as_str())
+                        .or(upload_id)
+                        .unwrap_or_default()
+                        .to_string(),
+                    progress: if size == 0 {
+                        100.0
+                    } else {
+                        size_uploaded as f64 / size as f64 * 100.0
+                    },
+                    size_uploaded,
+                    size_total: size,
+                    chunks_total,
+                    chunks_uploaded,
+                });
+            }
+        };
+
+        if size <= chunk_size {
+            let data = file.read_data().await?;
+            let result = self
+                .send_file_part(path, &headers, &params, param_name, file, data, cancellation)
+                .await?;
+            report(&result, size, 1);
+            return Ok(result);
+        }
+
+        // Ask the server how much of a previous attempt already landed. Only a
+        // missing file means there is nothing to resume.
+        let mut offset = 0;
+        let mut chunks_uploaded = 0;
+        if let Some(upload_id) = upload_id.filter(|id| !id.is_empty() && *id != "unique()") {
+            match self
+                .call("get", &format!("{}/{}", path, upload_id), None, None)
+                .await
+            {
+                Ok(existing) => {
+                    // A file with the same ID but other contents is not an
+                    // interrupted attempt of this upload
+                    let same_file = existing.get("sizeOriginal").and_then(|s| s.as_u64()) == Some(size)
+                        && existing.get("name").and_then(|n| n.as_str()) == Some(file.filename());
+                    if !same_file {
+                        return Err(AppwriteError::api_with_details(
+                            format!("A different file with the ID `{}` already exists", upload_id),
+                            409,
+                            Some("storage_file_already_exists".to_string()),
+                            None,
+                        ));
+                    }
+
+                    chunks_uploaded = existing
+                        .get("chunksUploaded")
+                        .and_then(|c| c.as_u64())
+                        .unwrap_or(0);
+                    offset = chunks_uploaded * chunk_size;
+                    if offset >= size {
+                        report(&existing, size, chunks_total);
+                        return Ok(existing);
+                    }
+                    if chunks_uploaded > 0 {
+                        headers.insert("x-appwrite-id".to_string(), upload_id.to_string());
+                    }
+                }
+                Err(e) if e.code() == Some(404) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut result = Value::Null;
+        while offset < size {
+            if cancellation.is_some_and(|token| token.is_cancelled()) {
+                return Err(AppwriteError::Cancelled);
+            }
+
+            let data = file.read_chunk(offset, chunk_size as usize).await?;
+            if data.is_empty() {
+                return Err(AppwriteError::file_error(format!(
+                    "Unexpected end of file at byte {} of {}",
+                    offset, size
+                )));
+            }
+
+            let end = offset + data.len() as u64;
+            headers.insert(
+                "content-range".to_string(),
+                format!("bytes {}-{}/{}", offset, end - 1, size),
+            );
+
+            result = self
+                .send_file_part(path, &headers, &params, param_name, file, data, cancellation)
+                .await?;
+            offset = end;
+            chunks_uploaded += 1;
+
+            if let Some(id) = result.get("$id").and_then(|id| id.as_str()) {
+                headers.insert("x-appwrite-id".to_string(), id.to_string());
+            }
+            report(&result, offset, chunks_uploaded);
+        }
+
+        Ok(result)
+    }
+
+    /// Send a single multipart request carrying `data` as the file part
+    ///
+    /// Chunks carry a `content-range` header and can be safely resent, so
+    /// they are retried regardless of the retryable methods of the policy.
+    #[allow(clippy::too_many_arguments)]
+    async fn send_file_part(
+        &self,
+        path: &str,
+        headers: &HashMap<String, String>,
+        params: &Map<String, Value>,
+        param_name: &str,
+        file: &InputFile,
+        data: Bytes,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<Value> {
+        let part = FilePart {
+            param_name: param_name.to_string(),
+            filename: file.filename().to_string(),
+            mime_type: file.mime_type().map(|m| m.to_string()),
+            data,
+        };
+
+        let is_chunk = headers.contains_key("content-range");
+        let send = async {
+            let response = self
+                .send("post", path, Some(headers.clone()), Some(params.clone()), Some(part), is_chunk)
+                .await?;
+            self.handle_response(response).await
+        };
+
+        match cancellation {
+            Some(token) => tokio::select! {
+                _ = token.cancelled() => Err(AppwriteError::Cancelled),
+                result = send => result,
+            },
+            None => send.await,
+        }
+    }
+
+    /// Send a request, retrying transient failures according to the retry policy
+    ///
+    /// `idempotent` marks requests that may be retried whatever their method.
+    async fn send(
+        &self,
+        method: &str,
+        path: &str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<Map<String, Value>>,
+        file: Option<FilePart>,
+        idempotent: bool,
+    ) -> Result<Response> {
+        let policy = &self.retry_policy;
+        let retryable = idempotent || policy.retries_method(method);
+        let mut attempt = 1;
+
+        loop {
+            let request = self
+                .prepare_request(method, path, headers.clone(), params.clone(), file.clone())
+                .await?;
+            let can_retry = retryable && attempt < policy.max_attempts();
+
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire().await;
+            }
+
+            let result = request.send().await;
+            if let Ok(response) = &result {
+                if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+                    *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner()) = Some(rate_limit);
+                }
+            }
+
+            match result {
+                Ok(response) if can_retry && policy.retries_status(response.status().as_u16()) => {
+                    // A server asking to wait longer than the policy allows gets its answer back
+                    let status = response.status().as_u16();
+                    match policy.delay(attempt, Some((status, response.headers()))) {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => return Ok(response),
+                    }
+                }
+                Ok(response) => return Ok(response),
+                Err(e) if can_retry && (e.is_connect() || e.is_timeout() || e.is_request()) => {
+                    if let Some(delay) = policy.delay(attempt, None) {
+                        tokio::time::sleep(delay).await;
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Build a request with headers and body for the given method
+    async fn prepare_request(
+        &self,
+        method: &str,
+        path: &str,
+        headers: Option<HashMap<String, String>>,
+        params: Option<Map<String, Value>>,
+        file: Option<FilePart>,
+    ) -> Result<RequestBuilder> {
         let method = method.to_uppercase();
         let method = Method::from_bytes(method.as_bytes())
             .map_err(|_| AppwriteError::InvalidParameter(format!("Invalid HTTP method: {}", method)))?;
@@ -133,21 +529,30 @@ impl Client {
             all_headers.extend(custom_headers);
         }
 
-        // Add headers to request
+        let default_content_type = String::new();
+        let content_type = all_headers
+            .get("content-type")
+            .unwrap_or(&default_content_type)
+            .clone();
+
+        // Add headers to request. The multipart boundary is set by reqwest,
+        // so a bare multipart content type must not override it.
         for (key, value) in &all_headers {
+            if key == "content-type" && content_type.starts_with("multipart/form-data") {
+                continue;
+            }
             request = request.header(key, value);
         }
 
         // Handle request body based on method and content type
         if method != Method::GET {
-            if let Some(params) = params {
-                let default_content_type = String::new();
-                let content_type = all_headers.get("content-type").unwrap_or(&default_content_type);
-                
+            if content_type.starts_with("multipart/form-data") {
+                request = self
+                    .build_multipart_request(request, params.unwrap_or_default(), file)
+                    .await?;
+            } else if let Some(params) = params {
                 if content_type.starts_with("application/json") {
                     request = request.json(&params);
-                } else if content_type.starts_with("multipart/form-data") {
-                    request = self.build_multipart_request(request, params).await?;
                 } else {
                     // Default to form data
                     let form_data = self.flatten_params(&params);
@@ -160,85 +565,96 @@ impl Client {
             request = request.query(&query_params);
         }
 
-        // Configure SSL verification 
-        // Note: reqwest doesn't expose danger_accept_invalid_certs in the public API
-        // This would need to be configured at the client level if needed
-
-        // Execute request
-        let response = request.send().await?;
-        
-        self.handle_response(response, response_type).await
+        Ok(request)
     }
 
     /// Handle the HTTP response
-    async fn handle_response(&self, response: Response, response_type: Option<&str>) -> Result<Value> {
-        // Check for warnings
+    async fn handle_response(&self, response: Response) -> Result<Value> {
+        self.log_warnings(&response);
+
+        if !response.status().is_success() {
+            return Err(self.error_from_response(response).await);
+        }
+
+        if content_type(&response).starts_with("application/json") {
+            // Deletes answer with 204 No Content
+            let body = response.bytes().await?;
+            if body.is_empty() {
+                return Ok(Value::Null);
+            }
+            Ok(serde_json::from_slice(&body)?)
+        } else {
+            // For non-JSON responses, return as string
+            let text = response.text().await?;
+            Ok(Value::String(text))
+        }
+    }
+
+    /// Pass a successful response through, turning error statuses into errors
+    async fn check_response(&self, response: Response) -> Result<Response> {
+        self.log_warnings(&response);
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(self.error_from_response(response).await)
+        }
+    }
+
+    /// Print any warnings the server attached to the response
+    fn log_warnings(&self, response: &Response) {
         if let Some(warnings) = response.headers().get("x-appwrite-warning") {
             if let Ok(warning_str) = warnings.to_str() {
                 for warning in warning_str.split(';') {
-                    eprintln!("Warning: {}", warning);
+                    self.warn(warning);
                 }
             }
         }
+    }
 
-        let status = response.status();
-        let content_type = response.headers()
-            .get("content-type")
-            .and_then(|ct| ct.to_str().ok())
-            .unwrap_or("")
-            .to_string(); // Clone to avoid borrow issues
-
-        // Handle different response types
-        match response_type {
-            Some("location") => {
-                let location = response.headers()
-                    .get("location")
-                    .and_then(|loc| loc.to_str().ok())
-                    .unwrap_or("");
-                return Ok(Value::String(location.to_string()));
-            }
-            _ => {}
-        }
+    /// Print a warning the caller cannot otherwise be told about
+    pub(crate) fn warn(&self, warning: impl std::fmt::Display) {
+        eprintln!("Warning: {}", warning);
+    }
 
-        if status.is_success() {
-            if content_type.starts_with("application/json") {
-                let json_value: Value = response.json().await?;
-                Ok(json_value)
-            } else {
-                // For non-JSON responses, return as string
-                let text = response.text().await?;
-                Ok(Value::String(text))
-            }
-        } else {
-            // Handle error response
-            let response_text = response.text().await?;
-            
-            if content_type.starts_with("application/json") {
-                if let Ok(error_json) = serde_json::from_str::<Value>(&response_text) {
-                    let message = error_json.get("message")
-                        .and_then(|m| m.as_str())
-                        .unwrap_or("Unknown error")
-                        .to_string();
-                    let error_type = error_json.get("type")
-                        .and_then(|t| t.as_str())
-                        .map(|s| s.to_string());
-                    
-                    return Err(AppwriteError::api_with_details(
-                        message,
-                        status.as_u16(),
-                        error_type,
-                        Some(response_text),
-                    ));
-                }
+    /// Build an API error from an error response
+    async fn error_from_response(&self, response: Response) -> AppwriteError {
+        let status = response.status();
+        let content_type = content_type(&response);
+        let rate_limit = RateLimit::from_headers(response.headers());
+
+        let response_text = match response.text().await {
+            Ok(text) => text,
+            Err(e) => return e.into(),
+        };
+
+        if content_type.starts_with("application/json") {
+            if let Ok(error_json) = serde_json::from_str::<Value>(&response_text) {
+                let message = error_json.get("message")
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("Unknown error")
+                    .to_string();
+                let error_type = error_json.get("type")
+                    .and_then(|t| t.as_str())
+                    .map(|s| s.to_string());
+                
+                return AppwriteError::api_with_details(
+                    message,
+                    status.as_u16(),
+                    error_type,
+                    Some(response_text),
+                )
+                .with_rate_limit(rate_limit);
             }
-            
-            Err(AppwriteError::api_with_details(
-                response_text.clone(),
-                status.as_u16(),
-                None,
-                Some(response_text),
-            ))
         }
+        
+        AppwriteError::api_with_details(
+            response_text.clone(),
+            status.as_u16(),
+            None,
+            Some(response_text),
+        )
+        .with_rate_limit(rate_limit)
     }
 
     /// Build multipart form request for file uploads
@@ -246,6 +662,7 @@ impl Client {
         &self,
         mut request: RequestBuilder,
         params: Map<String, Value>,
+        file: Option<FilePart>,
     ) -> Result<RequestBuilder> {
         let mut form = reqwest::multipart::Form::new();
 
@@ -261,7 +678,12 @@ impl Client {
                     form = form.text(key, b.to_string());
                 }
                 Value::Array(_) | Value::Object(_) => {
-                    form = form.text(key, serde_json::to_string(&value)?);
+                    // Nested values are sent as `key[0]`, `key[name]`, ... fields
+                    let mut flattened = HashMap::new();
+                    self.flatten_value(&key, &value, &mut flattened);
+                    for (field, text) in flattened {
+                        form = form.text(field, text);
+                    }
                 }
                 Value::Null => {
                     // Skip null values
@@ -269,10 +691,35 @@ impl Client {
             }
         }
 
+        if let Some(file) = file {
+            let mut part = reqwest::multipart::Part::stream(file.data).file_name(file.filename);
+            if let Some(mime_type) = file.mime_type {
+                part = part.mime_str(&mime_type).map_err(|_| {
+                    AppwriteError::invalid_parameter(format!("Invalid MIME type: {}", mime_type))
+                })?;
+            }
+            form = form.part(file.param_name, part);
+        }
+
         request = request.multipart(form);
         Ok(request)
     }
 
+    /// Build the URL of a `GET` endpoint that a browser is sent to, such as
+    /// an OAuth2 redirect, with the project and `params` in the query string
+    pub(crate) fn browser_url(&self, path: &str, params: &Map<String, Value>) -> Result<String> {
+        let mut url = Url::parse(&format!("{}{}", self.endpoint, path))?;
+
+        let mut query: Vec<(String, String)> = self.flatten_params(params).into_iter().collect();
+        query.sort();
+        if let Some(project) = self.headers.get("x-appwrite-project") {
+            query.insert(0, ("project".to_string(), project.clone()));
+        }
+        url.query_pairs_mut().extend_pairs(query);
+
+        Ok(url.into())
+    }
+
     /// Flatten nested parameters for form data
     fn flatten_params(&self, params: &Map<String, Value>) -> HashMap<String, String> {
         let mut flattened = HashMap::new();
@@ -325,4 +772,43 @@ impl Client {
         self.chunk_size = size;
         self
     }
-}
\ No newline at end of file
+
+    /// Get the retry policy applied to requests
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Set the retry policy applied to requests
+    ///
+    /// Requests are not retried unless a policy is set.
+    pub fn set_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Throttle requests through a client-side rate limiter
+    ///
+    /// Every request, including each retry and upload chunk, waits for a
+    /// token from the limiter before it is sent.
+    pub fn set_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Get the rate limit state reported by the most recent response
+    ///
+    /// Returns `None` until a response with `x-ratelimit-*` headers arrives.
+    /// Clones of a client share this state.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        *self.rate_limit.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Get the content type of a response, or an empty string
+fn content_type(response: &Response) -> String {
+    response.headers()
+        .get("content-type")
+        .and_then(|ct| ct.to_str().ok())
+        .unwrap_or("")
+        .to_string()
+}
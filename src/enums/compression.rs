@@ -3,8 +3,9 @@
 use serde::{Deserialize, Serialize};
 
 /// Compression types for storage
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Compression {
+    #[default]
     #[serde(rename = "none")]
     None,
     #[serde(rename = "gzip")]
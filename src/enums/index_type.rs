@@ -3,14 +3,20 @@
 use serde::{Deserialize, Serialize};
 
 /// Database index types
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum IndexType {
+    #[default]
     #[serde(rename = "key")]
     Key,
     #[serde(rename = "fulltext")]
     Fulltext,
     #[serde(rename = "unique")]
     Unique,
+    #[serde(rename = "spatial")]
+    Spatial,
+    /// Index type unknown to this SDK
+    #[serde(rename = "unknown", other)]
+    Unknown,
 }
 
 impl AsRef<str> for IndexType {
@@ -19,6 +25,8 @@ impl AsRef<str> for IndexType {
             IndexType::Key => "key",
             IndexType::Fulltext => "fulltext",
             IndexType::Unique => "unique",
+            IndexType::Spatial => "spatial",
+            IndexType::Unknown => "unknown",
         }
     }
 }
\ No newline at end of file
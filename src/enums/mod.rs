@@ -11,7 +11,9 @@ pub mod image_gravity;
 pub mod index_type;
 pub mod runtime;
 pub mod relationship_type;
+pub mod relation_mutate;
 pub mod compression;
+pub mod sha_version;
 
 pub use oauth_provider::OAuthProvider;
 pub use authenticator_type::AuthenticatorType;
@@ -24,4 +26,6 @@ pub use image_gravity::ImageGravity;
 pub use index_type::IndexType;
 pub use runtime::Runtime;
 pub use relationship_type::RelationshipType;
-pub use compression::Compression;
\ No newline at end of file
+pub use relation_mutate::RelationMutate;
+pub use compression::Compression;
+pub use sha_version::ShaVersion;
\ No newline at end of file
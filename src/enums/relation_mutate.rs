@@ -0,0 +1,25 @@
+//! Relation mutate enum
+
+use serde::{Deserialize, Serialize};
+
+/// What happens to related documents when a document is deleted
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RelationMutate {
+    #[serde(rename = "cascade")]
+    Cascade,
+    #[default]
+    #[serde(rename = "restrict")]
+    Restrict,
+    #[serde(rename = "setNull")]
+    SetNull,
+}
+
+impl AsRef<str> for RelationMutate {
+    fn as_ref(&self) -> &str {
+        match self {
+            RelationMutate::Cascade => "cascade",
+            RelationMutate::Restrict => "restrict",
+            RelationMutate::SetNull => "setNull",
+        }
+    }
+}
@@ -3,8 +3,9 @@
 use serde::{Deserialize, Serialize};
 
 /// Database relationship types
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RelationshipType {
+    #[default]
     #[serde(rename = "oneToOne")]
     OneToOne,
     #[serde(rename = "oneToMany")]
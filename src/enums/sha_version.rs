@@ -0,0 +1,49 @@
+//! SHA version enum
+
+use serde::{Deserialize, Serialize};
+
+/// SHA algorithms for imported password hashes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShaVersion {
+    #[serde(rename = "sha1")]
+    Sha1,
+    #[serde(rename = "sha224")]
+    Sha224,
+    #[default]
+    #[serde(rename = "sha256")]
+    Sha256,
+    #[serde(rename = "sha384")]
+    Sha384,
+    #[serde(rename = "sha512/224")]
+    Sha512_224,
+    #[serde(rename = "sha512/256")]
+    Sha512_256,
+    #[serde(rename = "sha512")]
+    Sha512,
+    #[serde(rename = "sha3-224")]
+    Sha3_224,
+    #[serde(rename = "sha3-256")]
+    Sha3_256,
+    #[serde(rename = "sha3-384")]
+    Sha3_384,
+    #[serde(rename = "sha3-512")]
+    Sha3_512,
+}
+
+impl AsRef<str> for ShaVersion {
+    fn as_ref(&self) -> &str {
+        match self {
+            ShaVersion::Sha1 => "sha1",
+            ShaVersion::Sha224 => "sha224",
+            ShaVersion::Sha256 => "sha256",
+            ShaVersion::Sha384 => "sha384",
+            ShaVersion::Sha512_224 => "sha512/224",
+            ShaVersion::Sha512_256 => "sha512/256",
+            ShaVersion::Sha512 => "sha512",
+            ShaVersion::Sha3_224 => "sha3-224",
+            ShaVersion::Sha3_256 => "sha3-256",
+            ShaVersion::Sha3_384 => "sha3-384",
+            ShaVersion::Sha3_512 => "sha3-512",
+        }
+    }
+}
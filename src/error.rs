@@ -1,13 +1,22 @@
 //! Error types for the Appwrite SDK using thiserror
 
 // use std::fmt; // Currently unused
+use crate::models::DocumentList;
+use crate::query_validator::QueryError;
+use crate::rate_limit::RateLimit;
 use thiserror::Error;
 
 /// Result type alias for Appwrite operations
 pub type Result<T> = std::result::Result<T, AppwriteError>;
 
 /// Appwrite SDK error types
+///
+/// New variants and new fields of the [`Api`](Self::Api) variant may be added
+/// in minor releases, so matches need a wildcard arm and `Api` patterns need
+/// `..`. Create API errors with [`AppwriteError::api`] or
+/// [`AppwriteError::api_with_details`].
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum AppwriteError {
     /// HTTP request error
     #[error("HTTP request failed: {0}")]
@@ -15,11 +24,14 @@ pub enum AppwriteError {
 
     /// API error from Appwrite server
     #[error("API error ({code}): {message}")]
+    #[non_exhaustive]
     Api {
         message: String,
         code: u16,
         error_type: Option<String>,
         response: Option<String>,
+        /// Rate limit headers of the failed response, if the server sent them
+        rate_limit: Option<RateLimit>,
     },
 
     /// JSON serialization/deserialization error
@@ -34,6 +46,10 @@ pub enum AppwriteError {
     #[error("Missing required parameter: {0}")]
     MissingParameter(String),
 
+    /// Queries rejected by a [`QueryValidator`](crate::QueryValidator)
+    #[error("Invalid queries: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    InvalidQuery(Vec<QueryError>),
+
     /// Invalid endpoint URL
     #[error("Invalid endpoint URL: {0}")]
     InvalidEndpoint(String),
@@ -50,6 +66,25 @@ pub enum AppwriteError {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// A bulk document write that failed after some batches were written
+    ///
+    /// `written` holds the documents of the batches before the failed one,
+    /// so a job can resume from `failed_batch`.
+    #[error("Bulk write failed at batch {failed_batch} after writing {} documents: {source}", .written.items.len())]
+    BulkWrite {
+        /// Documents written by the previous batches
+        written: DocumentList,
+        /// Index of the failed batch, starting at 0
+        failed_batch: usize,
+        /// Error of the failed batch
+        #[source]
+        source: Box<AppwriteError>,
+    },
+
+    /// Operation cancelled through a cancellation token
+    #[error("Operation cancelled")]
+    Cancelled,
+
     /// Generic error for other cases
     #[error("Appwrite error: {0}")]
     Generic(String),
@@ -63,6 +98,7 @@ impl AppwriteError {
             code,
             error_type: None,
             response: None,
+            rate_limit: None,
         }
     }
 
@@ -78,7 +114,16 @@ impl AppwriteError {
             code,
             error_type,
             response,
+            rate_limit: None,
+        }
+    }
+
+    /// Attach rate limit information to an API error
+    pub fn with_rate_limit(mut self, limit: Option<RateLimit>) -> Self {
+        if let Self::Api { rate_limit, .. } = &mut self {
+            *rate_limit = limit;
         }
+        self
     }
 
     /// Create a missing parameter error
@@ -97,9 +142,13 @@ impl AppwriteError {
     }
 
     /// Get the error code if this is an API error
+    ///
+    /// Like the other accessors, looks through [`BulkWrite`](Self::BulkWrite)
+    /// errors at the error of the failed batch.
     pub fn code(&self) -> Option<u16> {
         match self {
             Self::Api { code, .. } => Some(*code),
+            Self::BulkWrite { source, .. } => source.code(),
             _ => None,
         }
     }
@@ -108,6 +157,18 @@ impl AppwriteError {
     pub fn error_type(&self) -> Option<&str> {
         match self {
             Self::Api { error_type, .. } => error_type.as_deref(),
+            Self::BulkWrite { source, .. } => source.error_type(),
+            _ => None,
+        }
+    }
+
+    /// Get the rate limit state if this is an API error that reported it
+    ///
+    /// For `429 Too Many Requests` errors, `reset` tells when to try again.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        match self {
+            Self::Api { rate_limit, .. } => *rate_limit,
+            Self::BulkWrite { source, .. } => source.rate_limit(),
             _ => None,
         }
     }
@@ -116,6 +177,7 @@ impl AppwriteError {
     pub fn response(&self) -> Option<&str> {
         match self {
             Self::Api { response, .. } => response.as_deref(),
+            Self::BulkWrite { source, .. } => source.response(),
             _ => None,
         }
     }
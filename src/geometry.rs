@@ -0,0 +1,134 @@
+//! Geometry types for spatial attributes and queries
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A point, stored by Appwrite as `[longitude, latitude]`
+///
+/// # Example
+/// ```rust
+/// use appwrite::Point;
+///
+/// let eiffel_tower = Point::new(2.2945, 48.8584);
+/// assert_eq!(serde_json::to_string(&eiffel_tower).unwrap(), "[2.2945,48.8584]");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "[f64; 2]", into = "[f64; 2]")]
+pub struct Point {
+    /// Longitude (x)
+    pub longitude: f64,
+    /// Latitude (y)
+    pub latitude: f64,
+}
+
+impl Point {
+    /// Create a point from its longitude and latitude
+    pub fn new(longitude: f64, latitude: f64) -> Self {
+        Self { longitude, latitude }
+    }
+}
+
+impl From<[f64; 2]> for Point {
+    fn from([longitude, latitude]: [f64; 2]) -> Self {
+        Self::new(longitude, latitude)
+    }
+}
+
+impl From<Point> for [f64; 2] {
+    fn from(point: Point) -> Self {
+        [point.longitude, point.latitude]
+    }
+}
+
+/// A line string, going through two or more points
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Line(pub Vec<Point>);
+
+impl Line {
+    /// Create a line through the points
+    pub fn new(points: Vec<Point>) -> Self {
+        Self(points)
+    }
+}
+
+/// A polygon, made of an outer ring and optional inner rings for holes
+///
+/// Each ring is closed: its last point equals its first point.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Polygon(pub Vec<Vec<Point>>);
+
+impl Polygon {
+    /// Create a polygon from its outer ring, closing the ring if needed
+    ///
+    /// # Example
+    /// ```rust
+    /// use appwrite::{Point, Polygon};
+    ///
+    /// let zone = Polygon::new(vec![
+    ///     Point::new(0.0, 0.0),
+    ///     Point::new(1.0, 0.0),
+    ///     Point::new(1.0, 1.0),
+    /// ]);
+    /// assert_eq!(zone.0[0].len(), 4);
+    /// ```
+    pub fn new(mut ring: Vec<Point>) -> Self {
+        if ring.len() > 1 && ring.first() != ring.last() {
+            ring.push(ring[0]);
+        }
+
+        Self(vec![ring])
+    }
+
+    /// Add an inner ring cutting a hole in the polygon
+    pub fn set_hole(mut self, ring: Vec<Point>) -> Self {
+        let Polygon(mut rings) = Self::new(ring);
+        self.0.append(&mut rings);
+        self
+    }
+}
+
+impl From<Point> for Value {
+    fn from(point: Point) -> Self {
+        serde_json::json!([point.longitude, point.latitude])
+    }
+}
+
+impl From<Line> for Value {
+    fn from(line: Line) -> Self {
+        Value::Array(line.0.into_iter().map(Value::from).collect())
+    }
+}
+
+impl From<Polygon> for Value {
+    fn from(polygon: Polygon) -> Self {
+        Value::Array(
+            polygon
+                .0
+                .into_iter()
+                .map(|ring| Value::Array(ring.into_iter().map(Value::from).collect()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_geometry_serialization() {
+        let line = Line::new(vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)]);
+        assert_eq!(serde_json::to_value(&line).unwrap(), json!([[1.0, 2.0], [3.0, 4.0]]));
+        assert_eq!(Value::from(line.clone()), serde_json::to_value(&line).unwrap());
+
+        let polygon = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0)])
+            .set_hole(vec![Point::new(1.0, 1.0), Point::new(2.0, 1.0), Point::new(2.0, 2.0)]);
+        let value = Value::from(polygon.clone());
+        assert_eq!(value[0], json!([[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 0.0]]));
+        assert_eq!(value[1][3], json!([1.0, 1.0]));
+        assert_eq!(serde_json::from_value::<Polygon>(value).unwrap(), polygon);
+    }
+}
@@ -0,0 +1,124 @@
+//! ID helpers for Appwrite resources
+
+use crate::error::{AppwriteError, Result};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum length of a resource ID
+const MAX_LENGTH: usize = 36;
+
+/// ID helper functions
+///
+/// Resource IDs have at most 36 characters among `a-z`, `A-Z`, `0-9`, `.`,
+/// `-` and `_`, and cannot start with a special character.
+///
+/// # Example
+/// ```rust
+/// use appwrite::ID;
+///
+/// let id = ID::unique();
+/// assert_eq!(id.len(), 20);
+///
+/// assert_eq!(ID::custom("invoice-2024").unwrap(), "invoice-2024");
+/// assert!(ID::custom("-invoice").is_err());
+/// ```
+pub struct ID;
+
+impl ID {
+    /// Placeholder asking the server to generate the ID
+    ///
+    /// Only accepted as the ID of a resource being created, such as the
+    /// `document_id` of [`Databases::create_document`](crate::Databases::create_document).
+    pub const SERVER_GENERATED: &'static str = "unique()";
+
+    /// Generate a unique ID
+    ///
+    /// Like other Appwrite SDKs, the ID is the current time in hexadecimal
+    /// followed by 7 random hexadecimal digits, so IDs sort by creation time.
+    /// Unlike [`ID::SERVER_GENERATED`], the ID is known before the request is
+    /// sent.
+    pub fn unique() -> String {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let random = uuid::Uuid::new_v4().as_u128() & 0xfff_ffff;
+
+        format!("{:x}{:05x}{:07x}", now.as_secs(), now.subsec_millis(), random)
+    }
+
+    /// Use a custom ID, checking that it is valid
+    pub fn custom(id: impl Into<String>) -> Result<String> {
+        let id = id.into();
+        if !is_valid(&id) {
+            return Err(invalid_id("id", &id));
+        }
+
+        Ok(id)
+    }
+}
+
+/// Whether `id` is a valid resource ID
+pub(crate) fn is_valid(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_LENGTH
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+        && !id.starts_with(['.', '-', '_'])
+}
+
+/// Check the ID of an existing resource
+pub(crate) fn validate_id(id: &str, param: &str) -> Result<()> {
+    if id.is_empty() {
+        return Err(AppwriteError::missing_parameter(param));
+    }
+    if !is_valid(id) {
+        return Err(invalid_id(param, id));
+    }
+
+    Ok(())
+}
+
+/// Check the ID of a resource being created, which may also be
+/// [`ID::SERVER_GENERATED`] to let the server generate it
+pub(crate) fn validate_new_id(id: &str, param: &str) -> Result<()> {
+    if id == ID::SERVER_GENERATED {
+        return Ok(());
+    }
+
+    validate_id(id, param)
+}
+
+fn invalid_id(param: &str, id: &str) -> AppwriteError {
+    AppwriteError::invalid_parameter(format!(
+        "{} `{}` must have at most {} characters among a-z, A-Z, 0-9, '.', '-' and '_', \
+         and cannot start with a special character",
+        param, id, MAX_LENGTH
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique() {
+        let first = ID::unique();
+        let second = ID::unique();
+
+        assert!(is_valid(&first));
+        assert_eq!(first.len(), 20);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_validate_id() {
+        assert!(validate_id("movie_1.v2", "document_id").is_ok());
+        assert!(validate_new_id("movie_1.v2", "document_id").is_ok());
+        assert!(validate_new_id(ID::SERVER_GENERATED, "document_id").is_ok());
+        assert!(validate_id(ID::SERVER_GENERATED, "document_id").is_err());
+        assert!(validate_new_id("", "document_id").is_err());
+        assert!(validate_id(&"a".repeat(36), "document_id").is_ok());
+
+        assert!(matches!(validate_id("", "document_id"), Err(AppwriteError::MissingParameter(_))));
+        assert!(matches!(validate_id("_movie", "document_id"), Err(AppwriteError::InvalidParameter(_))));
+        assert!(validate_id(&"a".repeat(37), "document_id").is_err());
+        assert!(validate_id("movie 1", "document_id").is_err());
+        assert!(ID::custom(ID::SERVER_GENERATED).is_err());
+    }
+}
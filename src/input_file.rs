@@ -101,7 +101,7 @@ impl InputFile {
             InputFileSource::Bytes => {
                 self.data.as_ref()
                     .ok_or_else(|| AppwriteError::file_error("Data not set"))
-                    .map(|data| data.clone())
+                    .cloned()
             }
         }
     }
@@ -32,21 +32,36 @@
 
 pub mod client;
 pub mod error;
+pub mod id;
 pub mod query;
+pub mod query_validator;
 pub mod permission;
 pub mod input_file;
+pub mod geometry;
+pub mod password_hash;
+pub mod retry;
+pub mod rate_limit;
+pub mod pagination;
 pub mod services;
 pub mod enums;
+pub mod models;
 
-pub use client::Client;
+pub use client::{Client, UploadProgress};
 pub use error::{AppwriteError, Result};
-pub use query::Query;
-pub use permission::{Permission, Role};
+pub use id::ID;
+pub use query::{Query, QueryDateTime};
+pub use query_validator::{QueryError, QueryValidator};
+pub use permission::{Permission, PermissionAction, Principal, Role};
 pub use input_file::InputFile;
+pub use geometry::{Line, Point, Polygon};
+pub use password_hash::PasswordHash;
+pub use retry::RetryPolicy;
+pub use rate_limit::{RateLimit, RateLimiter};
+pub use tokio_util::sync::CancellationToken;
 
 // Re-export all services for convenience
 pub use services::{
-    Account, Avatars, Databases, Functions, GraphQL, Health, 
+    Account, Avatars, DatabaseTransaction, Databases, Functions, GraphQL, Health, 
     Locale, Messaging, Sites, Storage, Teams, Tokens, Users
 };
 
@@ -54,5 +69,5 @@ pub use services::{
 pub use enums::{
     OAuthProvider, AuthenticatorType, AuthenticationFactor,
     Browser, CreditCard, Flag, ImageFormat, ImageGravity,
-    IndexType, Runtime, RelationshipType, Compression
+    IndexType, Runtime, RelationshipType, RelationMutate, Compression, ShaVersion
 };
\ No newline at end of file
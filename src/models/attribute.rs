@@ -0,0 +1,557 @@
+//! Attribute models
+
+use super::list::ListItem;
+use crate::enums::{RelationMutate, RelationshipType};
+use crate::geometry::{Line, Point, Polygon};
+use serde::de::{Deserializer, Error as _};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A collection attribute of any type
+///
+/// Attributes are told apart by their `type` and, for string based
+/// attributes, their `format`. Types this SDK does not know yet are kept as
+/// [`Attribute::Other`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Attribute {
+    /// String attribute
+    String(AttributeString),
+    /// Email attribute
+    Email(AttributeEmail),
+    /// Enum attribute
+    Enum(AttributeEnum),
+    /// IP address attribute
+    Ip(AttributeIp),
+    /// URL attribute
+    Url(AttributeUrl),
+    /// Integer attribute
+    Integer(AttributeInteger),
+    /// Float attribute
+    Float(AttributeFloat),
+    /// Boolean attribute
+    Boolean(AttributeBoolean),
+    /// Datetime attribute
+    Datetime(AttributeDatetime),
+    /// Relationship attribute
+    Relationship(AttributeRelationship),
+    /// Point attribute
+    Point(AttributePoint),
+    /// Line attribute
+    Line(AttributeLine),
+    /// Polygon attribute
+    Polygon(AttributePolygon),
+    /// Attribute of a type unknown to this SDK
+    Other(Value),
+}
+
+impl Attribute {
+    /// Attribute key
+    pub fn key(&self) -> &str {
+        match self {
+            Attribute::String(a) => &a.key,
+            Attribute::Email(a) => &a.key,
+            Attribute::Enum(a) => &a.key,
+            Attribute::Ip(a) => &a.key,
+            Attribute::Url(a) => &a.key,
+            Attribute::Integer(a) => &a.key,
+            Attribute::Float(a) => &a.key,
+            Attribute::Boolean(a) => &a.key,
+            Attribute::Datetime(a) => &a.key,
+            Attribute::Relationship(a) => &a.key,
+            Attribute::Point(a) => &a.key,
+            Attribute::Line(a) => &a.key,
+            Attribute::Polygon(a) => &a.key,
+            Attribute::Other(value) => value.get("key").and_then(|k| k.as_str()).unwrap_or(""),
+        }
+    }
+
+    /// Attribute type, such as `string` or `integer`
+    pub fn attribute_type(&self) -> &str {
+        match self {
+            Attribute::String(a) => &a.attribute_type,
+            Attribute::Email(a) => &a.attribute_type,
+            Attribute::Enum(a) => &a.attribute_type,
+            Attribute::Ip(a) => &a.attribute_type,
+            Attribute::Url(a) => &a.attribute_type,
+            Attribute::Integer(a) => &a.attribute_type,
+            Attribute::Float(a) => &a.attribute_type,
+            Attribute::Boolean(a) => &a.attribute_type,
+            Attribute::Datetime(a) => &a.attribute_type,
+            Attribute::Relationship(a) => &a.attribute_type,
+            Attribute::Point(a) => &a.attribute_type,
+            Attribute::Line(a) => &a.attribute_type,
+            Attribute::Polygon(a) => &a.attribute_type,
+            Attribute::Other(value) => value.get("type").and_then(|t| t.as_str()).unwrap_or(""),
+        }
+    }
+
+    /// Whether the attribute holds an array of values
+    pub fn array(&self) -> bool {
+        match self {
+            Attribute::String(a) => a.array,
+            Attribute::Email(a) => a.array,
+            Attribute::Enum(a) => a.array,
+            Attribute::Ip(a) => a.array,
+            Attribute::Url(a) => a.array,
+            Attribute::Integer(a) => a.array,
+            Attribute::Float(a) => a.array,
+            Attribute::Boolean(a) => a.array,
+            Attribute::Datetime(a) => a.array,
+            Attribute::Relationship(a) => a.array,
+            Attribute::Point(a) => a.array,
+            Attribute::Line(a) => a.array,
+            Attribute::Polygon(a) => a.array,
+            Attribute::Other(value) => value.get("array").and_then(|a| a.as_bool()).unwrap_or(false),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Attribute {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let attribute_type = value.get("type").and_then(|t| t.as_str()).unwrap_or("");
+        let format = value.get("format").and_then(|f| f.as_str()).unwrap_or("");
+
+        let attribute = match (attribute_type, format) {
+            ("string", "email") => serde_json::from_value(value).map(Attribute::Email),
+            ("string", "enum") => serde_json::from_value(value).map(Attribute::Enum),
+            ("string", "ip") => serde_json::from_value(value).map(Attribute::Ip),
+            ("string", "url") => serde_json::from_value(value).map(Attribute::Url),
+            ("string", _) => serde_json::from_value(value).map(Attribute::String),
+            ("integer", _) => serde_json::from_value(value).map(Attribute::Integer),
+            ("double", _) => serde_json::from_value(value).map(Attribute::Float),
+            ("boolean", _) => serde_json::from_value(value).map(Attribute::Boolean),
+            ("datetime", _) => serde_json::from_value(value).map(Attribute::Datetime),
+            ("relationship", _) => serde_json::from_value(value).map(Attribute::Relationship),
+            ("point", _) => serde_json::from_value(value).map(Attribute::Point),
+            ("linestring", _) => serde_json::from_value(value).map(Attribute::Line),
+            ("polygon", _) => serde_json::from_value(value).map(Attribute::Polygon),
+            _ => Ok(Attribute::Other(value)),
+        };
+
+        attribute.map_err(D::Error::custom)
+    }
+}
+
+impl ListItem for Attribute {
+    const LIST_KEY: &'static str = "attributes";
+}
+
+/// A string attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeString {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Maximum length in characters
+    pub size: u64,
+    /// Default value
+    pub default: Option<String>,
+    /// Whether the value is encrypted at rest
+    pub encrypt: bool,
+}
+
+/// An email attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeEmail {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// String format
+    pub format: String,
+    /// Default value
+    pub default: Option<String>,
+}
+
+/// An enum attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeEnum {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Allowed values
+    pub elements: Vec<String>,
+    /// String format
+    pub format: String,
+    /// Default value
+    pub default: Option<String>,
+}
+
+/// An IP address attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeIp {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// String format
+    pub format: String,
+    /// Default value
+    pub default: Option<String>,
+}
+
+/// A URL attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeUrl {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// String format
+    pub format: String,
+    /// Default value
+    pub default: Option<String>,
+}
+
+/// An integer attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeInteger {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Minimum value
+    pub min: Option<i64>,
+    /// Maximum value
+    pub max: Option<i64>,
+    /// Default value
+    pub default: Option<i64>,
+}
+
+/// A float attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeFloat {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Minimum value
+    pub min: Option<f64>,
+    /// Maximum value
+    pub max: Option<f64>,
+    /// Default value
+    pub default: Option<f64>,
+}
+
+/// A boolean attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeBoolean {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Default value
+    pub default: Option<bool>,
+}
+
+/// A datetime attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeDatetime {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Datetime format
+    pub format: String,
+    /// Default value in ISO 8601 format
+    pub default: Option<String>,
+}
+
+/// A relationship attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeRelationship {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// ID of the related collection
+    pub related_collection: String,
+    /// Relationship type
+    pub relation_type: RelationshipType,
+    /// Whether the relationship is visible from the related collection
+    pub two_way: bool,
+    /// Key of the attribute in the related collection
+    pub two_way_key: String,
+    /// What happens to related documents on delete
+    pub on_delete: RelationMutate,
+    /// Side of the relationship: `parent` or `child`
+    pub side: String,
+}
+
+/// A point attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributePoint {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Default value
+    pub default: Option<Point>,
+}
+
+/// A line attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributeLine {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Default value
+    pub default: Option<Line>,
+}
+
+/// A polygon attribute
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AttributePolygon {
+    /// Attribute key
+    pub key: String,
+    /// Attribute type
+    #[serde(rename = "type")]
+    pub attribute_type: String,
+    /// Attribute status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Whether the attribute is required
+    pub required: bool,
+    /// Whether the attribute holds an array of values
+    pub array: bool,
+    /// Attribute creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Attribute update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Default value
+    pub default: Option<Polygon>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_attribute_dispatch() {
+        let email: Attribute = serde_json::from_value(json!({
+            "key": "email", "type": "string", "format": "email", "required": true,
+        }))
+        .unwrap();
+        assert!(matches!(email, Attribute::Email(_)));
+        assert_eq!(email.key(), "email");
+
+        let count: Attribute = serde_json::from_value(json!({
+            "key": "count", "type": "integer", "array": true,
+        }))
+        .unwrap();
+        assert!(matches!(count, Attribute::Integer(_)));
+        assert!(count.array());
+
+        let point: Attribute = serde_json::from_value(json!({
+            "key": "location", "type": "point", "default": [2.2945, 48.8584],
+        }))
+        .unwrap();
+        assert!(matches!(&point, Attribute::Point(a) if a.default == Some(Point::new(2.2945, 48.8584))));
+
+        let vector: Attribute = serde_json::from_value(json!({"key": "embedding", "type": "vector"})).unwrap();
+        assert!(matches!(vector, Attribute::Other(_)));
+        assert_eq!(vector.attribute_type(), "vector");
+    }
+}
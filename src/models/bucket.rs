@@ -0,0 +1,44 @@
+//! Bucket model
+
+use super::list::ListItem;
+use crate::enums::Compression;
+use crate::permission::Permission;
+use serde::{Deserialize, Serialize};
+
+/// A storage bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Bucket {
+    /// Bucket ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Bucket creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Bucket update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Bucket permissions
+    #[serde(rename = "$permissions")]
+    pub permissions: Vec<Permission>,
+    /// Whether file-level permissions apply
+    pub file_security: bool,
+    /// Bucket name
+    pub name: String,
+    /// Whether the bucket is enabled
+    pub enabled: bool,
+    /// Maximum file size in bytes
+    pub maximum_file_size: u64,
+    /// Allowed file extensions
+    pub allowed_file_extensions: Vec<String>,
+    /// Compression algorithm applied to stored files
+    pub compression: Compression,
+    /// Whether files are encrypted at rest
+    pub encryption: bool,
+    /// Whether files are scanned for viruses
+    pub antivirus: bool,
+}
+
+impl ListItem for Bucket {
+    const LIST_KEY: &'static str = "buckets";
+}
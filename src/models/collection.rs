@@ -0,0 +1,39 @@
+//! Collection model
+
+use super::{list::ListItem, Attribute, Index};
+use crate::permission::Permission;
+use serde::{Deserialize, Serialize};
+
+/// A collection of documents
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Collection {
+    /// Collection ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Collection creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Collection update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Collection permissions
+    #[serde(rename = "$permissions")]
+    pub permissions: Vec<Permission>,
+    /// Database ID
+    pub database_id: String,
+    /// Collection name
+    pub name: String,
+    /// Whether the collection is enabled
+    pub enabled: bool,
+    /// Whether document-level permissions apply
+    pub document_security: bool,
+    /// Collection attributes
+    pub attributes: Vec<Attribute>,
+    /// Collection indexes
+    pub indexes: Vec<Index>,
+}
+
+impl ListItem for Collection {
+    const LIST_KEY: &'static str = "collections";
+}
@@ -0,0 +1,27 @@
+//! Database model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+
+/// A database
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Database {
+    /// Database ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Database name
+    pub name: String,
+    /// Database creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Database update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Whether the database is enabled
+    pub enabled: bool,
+}
+
+impl ListItem for Database {
+    const LIST_KEY: &'static str = "databases";
+}
@@ -0,0 +1,147 @@
+//! Document model
+
+use super::list::ListItem;
+use crate::permission::Permission;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A document in a collection
+///
+/// System attributes are exposed as fields, and the attributes defined by the
+/// collection are deserialized into `data`. `T` defaults to a JSON map, and
+/// can be any type matching the collection's attributes:
+///
+/// ```rust
+/// use appwrite::models::Document;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Movie {
+///     title: String,
+///     year: u32,
+/// }
+///
+/// let document: Document<Movie> = serde_json::from_str(r#"{
+///     "$id": "movie-1",
+///     "$collectionId": "movies",
+///     "title": "Alien",
+///     "year": 1979
+/// }"#).unwrap();
+///
+/// assert_eq!(document.id, "movie-1");
+/// assert_eq!(document.data.year, 1979);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Document<T = Map<String, Value>> {
+    /// Document ID
+    #[serde(rename = "$id", default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    /// Collection ID
+    #[serde(rename = "$collectionId", default, skip_serializing_if = "String::is_empty")]
+    pub collection_id: String,
+    /// Database ID
+    #[serde(rename = "$databaseId", default, skip_serializing_if = "String::is_empty")]
+    pub database_id: String,
+    /// Document creation date in ISO 8601 format
+    #[serde(rename = "$createdAt", default, skip_serializing_if = "String::is_empty")]
+    pub created_at: String,
+    /// Document update date in ISO 8601 format
+    #[serde(rename = "$updatedAt", default, skip_serializing_if = "String::is_empty")]
+    pub updated_at: String,
+    /// Document permissions
+    #[serde(rename = "$permissions", default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<Permission>,
+    /// Document attributes
+    #[serde(flatten)]
+    pub data: T,
+}
+
+impl<T> Document<T> {
+    /// Create a document to send in a bulk request
+    ///
+    /// An empty `id` lets the server generate one. System fields left empty
+    /// are not serialized.
+    pub fn new(id: impl Into<String>, data: T) -> Self {
+        Self {
+            id: id.into(),
+            collection_id: String::new(),
+            database_id: String::new(),
+            created_at: String::new(),
+            updated_at: String::new(),
+            permissions: Vec::new(),
+            data,
+        }
+    }
+
+    /// Set the document permissions
+    pub fn set_permissions(mut self, permissions: Vec<Permission>) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
+    /// Take the document attributes, dropping the system fields
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+impl<T> ListItem for Document<T> {
+    const LIST_KEY: &'static str = "documents";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Movie {
+        title: String,
+        year: u32,
+    }
+
+    #[test]
+    fn test_typed_document() {
+        let value = json!({
+            "$id": "movie-1",
+            "$collectionId": "movies",
+            "$databaseId": "main",
+            "$createdAt": "2024-01-01T00:00:00.000+00:00",
+            "$updatedAt": "2024-01-01T00:00:00.000+00:00",
+            "$permissions": ["read(\"any\")"],
+            "title": "Alien",
+            "year": 1979,
+        });
+
+        let document: Document<Movie> = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(document.id, "movie-1");
+        assert_eq!(document.permissions, vec![Permission::read(crate::Role::any())]);
+        assert_eq!(document.data, Movie { title: "Alien".to_string(), year: 1979 });
+        assert_eq!(serde_json::to_value(&document).unwrap(), value);
+
+        let new = Document::new("movie-2", Movie { title: "Aliens".to_string(), year: 1986 });
+        assert_eq!(
+            serde_json::to_value(&new).unwrap(),
+            json!({"$id": "movie-2", "title": "Aliens", "year": 1986})
+        );
+
+        let untyped: Document = serde_json::from_value(value).unwrap();
+        assert_eq!(untyped.data["title"], "Alien");
+        assert!(!untyped.data.contains_key("$id"));
+    }
+
+    #[test]
+    fn test_unexpected_permissions_are_kept() {
+        let document: Document = serde_json::from_value(json!({
+            "$id": "movie-1",
+            "$permissions": ["read(\"any\")", "update(\"robot:42\")"],
+        }))
+        .unwrap();
+
+        assert_eq!(document.permissions[1], "update(\"robot:42\")");
+        assert!(document.permissions[1].validate().is_err());
+
+        let invalid = serde_json::from_value::<Document>(json!({"$permissions": ["any"]}));
+        assert!(invalid.is_err());
+    }
+}
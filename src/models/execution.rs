@@ -0,0 +1,63 @@
+//! Execution model
+
+use super::list::ListItem;
+use crate::permission::Permission;
+use serde::{Deserialize, Serialize};
+
+/// An HTTP header
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Headers {
+    /// Header name
+    pub name: String,
+    /// Header value
+    pub value: String,
+}
+
+/// A function execution
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Execution {
+    /// Execution ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Execution creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Execution update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Execution permissions
+    #[serde(rename = "$permissions")]
+    pub permissions: Vec<Permission>,
+    /// Function ID
+    pub function_id: String,
+    /// What triggered the execution: `http`, `schedule` or `event`
+    pub trigger: String,
+    /// Execution status: `waiting`, `processing`, `completed` or `failed`
+    pub status: String,
+    /// HTTP request method
+    pub request_method: String,
+    /// HTTP request path and query
+    pub request_path: String,
+    /// HTTP request headers
+    pub request_headers: Vec<Headers>,
+    /// HTTP response status code
+    pub response_status_code: u16,
+    /// HTTP response body
+    pub response_body: String,
+    /// HTTP response headers
+    pub response_headers: Vec<Headers>,
+    /// Function logs
+    pub logs: String,
+    /// Function errors
+    pub errors: String,
+    /// Execution duration in seconds
+    pub duration: f64,
+    /// Scheduled execution date in ISO 8601 format
+    pub scheduled_at: Option<String>,
+}
+
+impl ListItem for Execution {
+    const LIST_KEY: &'static str = "executions";
+}
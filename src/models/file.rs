@@ -0,0 +1,41 @@
+//! File model
+
+use super::list::ListItem;
+use crate::permission::Permission;
+use serde::{Deserialize, Serialize};
+
+/// A file stored in a bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct File {
+    /// File ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Bucket ID
+    pub bucket_id: String,
+    /// File creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// File update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// File permissions
+    #[serde(rename = "$permissions")]
+    pub permissions: Vec<Permission>,
+    /// File name
+    pub name: String,
+    /// File MD5 signature
+    pub signature: String,
+    /// File MIME type
+    pub mime_type: String,
+    /// File original size in bytes
+    pub size_original: u64,
+    /// Total number of chunks
+    pub chunks_total: u64,
+    /// Number of chunks uploaded so far
+    pub chunks_uploaded: u64,
+}
+
+impl ListItem for File {
+    const LIST_KEY: &'static str = "files";
+}
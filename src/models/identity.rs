@@ -0,0 +1,37 @@
+//! Identity model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+
+/// A user identity from an OAuth2 provider
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Identity {
+    /// Identity ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Identity creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Identity update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// User ID
+    pub user_id: String,
+    /// Identity provider
+    pub provider: String,
+    /// User ID at the identity provider
+    pub provider_uid: String,
+    /// Email address at the identity provider
+    pub provider_email: String,
+    /// Identity provider access token
+    pub provider_access_token: String,
+    /// Identity provider access token expiration date in ISO 8601 format
+    pub provider_access_token_expiry: String,
+    /// Identity provider refresh token
+    pub provider_refresh_token: String,
+}
+
+impl ListItem for Identity {
+    const LIST_KEY: &'static str = "identities";
+}
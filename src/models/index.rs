@@ -0,0 +1,55 @@
+//! Index model
+
+use super::list::ListItem;
+use crate::enums::IndexType;
+use serde::{Deserialize, Serialize};
+
+/// A collection index
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Index {
+    /// Index key
+    pub key: String,
+    /// Index type
+    #[serde(rename = "type")]
+    pub index_type: IndexType,
+    /// Index status: `available`, `processing`, `deleting`, `stuck` or `failed`
+    pub status: String,
+    /// Error message explaining a failed status
+    pub error: String,
+    /// Indexed attribute keys
+    pub attributes: Vec<String>,
+    /// Index lengths for each attribute
+    pub lengths: Vec<Option<u64>>,
+    /// Index orders for each attribute
+    pub orders: Vec<Option<String>>,
+    /// Index creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Index update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+}
+
+impl ListItem for Index {
+    const LIST_KEY: &'static str = "indexes";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_unknown_index_type() {
+        let indexes: Vec<Index> = serde_json::from_value(json!([
+            {"key": "title", "type": "fulltext", "attributes": ["title"]},
+            {"key": "embedding", "type": "vector", "attributes": ["embedding"]},
+        ]))
+        .unwrap();
+
+        assert_eq!(indexes[0].index_type, IndexType::Fulltext);
+        assert_eq!(indexes[1].index_type, IndexType::Unknown);
+        assert_eq!(indexes[1].key, "embedding");
+    }
+}
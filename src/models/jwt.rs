@@ -0,0 +1,11 @@
+//! JWT model
+
+use serde::{Deserialize, Serialize};
+
+/// A JSON Web Token to authenticate as a user
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Jwt {
+    /// The JWT
+    pub jwt: String,
+}
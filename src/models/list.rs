@@ -0,0 +1,114 @@
+//! Paginated list wrapper
+
+use serde::de::{self, DeserializeOwned, Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A model that can appear in a list response
+pub trait ListItem {
+    /// Key holding the items in a list response, such as `"users"`
+    const LIST_KEY: &'static str;
+}
+
+/// A page of results from a list endpoint
+///
+/// Appwrite returns lists as `{"total": 2, "users": [...]}`, where the key
+/// holding the items depends on the model. `items` holds them whatever the
+/// key, and `total` counts all matching results, not just this page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct List<T> {
+    /// Total number of results matching the query
+    pub total: u64,
+    /// Results in this page
+    pub items: Vec<T>,
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self {
+            total: 0,
+            items: Vec::new(),
+        }
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<T: ListItem + Serialize> Serialize for List<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("total", &self.total)?;
+        map.serialize_entry(T::LIST_KEY, &self.items)?;
+        map.end()
+    }
+}
+
+impl<'de, T: ListItem + DeserializeOwned> Deserialize<'de> for List<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ListVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: ListItem + DeserializeOwned> Visitor<'de> for ListVisitor<T> {
+            type Value = List<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a list of {}", T::LIST_KEY)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<List<T>, A::Error> {
+                let mut total = None;
+                let mut items = None;
+
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "total" {
+                        total = Some(map.next_value()?);
+                    } else if key == T::LIST_KEY {
+                        items = Some(map.next_value()?);
+                    } else {
+                        map.next_value::<de::IgnoredAny>()?;
+                    }
+                }
+
+                Ok(List {
+                    total: total.ok_or_else(|| de::Error::missing_field("total"))?,
+                    items: items.ok_or_else(|| de::Error::missing_field(T::LIST_KEY))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(ListVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::{User, UserList};
+    use serde_json::json;
+
+    #[test]
+    fn test_list_uses_model_key() {
+        let list: UserList = serde_json::from_value(json!({
+            "total": 5,
+            "users": [{"$id": "user-1", "name": "Ada"}],
+        }))
+        .unwrap();
+
+        assert_eq!(list.total, 5);
+        assert_eq!(list.items[0].id, "user-1");
+        assert_eq!(serde_json::to_value(&list).unwrap()["users"][0]["name"], "Ada");
+
+        let missing = serde_json::from_value::<UserList>(json!({"total": 0, "documents": []}));
+        assert!(missing.is_err());
+
+        let users: Vec<User> = list.into_iter().collect();
+        assert_eq!(users.len(), 1);
+    }
+}
@@ -0,0 +1,56 @@
+//! Log model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+
+/// An audit log entry
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Log {
+    /// Event name
+    pub event: String,
+    /// ID of the user who triggered the event
+    pub user_id: String,
+    /// Email address of the user who triggered the event
+    pub user_email: String,
+    /// Name of the user who triggered the event
+    pub user_name: String,
+    /// API mode when the event was triggered
+    pub mode: String,
+    /// IP address the event was triggered from
+    pub ip: String,
+    /// Event date in ISO 8601 format
+    pub time: String,
+    /// Operating system code name
+    pub os_code: String,
+    /// Operating system name
+    pub os_name: String,
+    /// Operating system version
+    pub os_version: String,
+    /// Client type
+    pub client_type: String,
+    /// Client code name
+    pub client_code: String,
+    /// Client name
+    pub client_name: String,
+    /// Client version
+    pub client_version: String,
+    /// Client engine name
+    pub client_engine: String,
+    /// Client engine version
+    pub client_engine_version: String,
+    /// Device name
+    pub device_name: String,
+    /// Device brand name
+    pub device_brand: String,
+    /// Device model name
+    pub device_model: String,
+    /// Country code in ISO 3166-1 two-character format
+    pub country_code: String,
+    /// Country name
+    pub country_name: String,
+}
+
+impl ListItem for Log {
+    const LIST_KEY: &'static str = "logs";
+}
@@ -0,0 +1,43 @@
+//! Membership model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+
+/// A user's membership in a team
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Membership {
+    /// Membership ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Membership creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Membership update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// User ID
+    pub user_id: String,
+    /// User name
+    pub user_name: String,
+    /// User email address
+    pub user_email: String,
+    /// Team ID
+    pub team_id: String,
+    /// Team name
+    pub team_name: String,
+    /// Invitation date in ISO 8601 format
+    pub invited: String,
+    /// Date the invitation was accepted in ISO 8601 format
+    pub joined: String,
+    /// Whether the user has accepted the invitation
+    pub confirm: bool,
+    /// Whether the user has multi-factor authentication enabled
+    pub mfa: bool,
+    /// Roles of the user in the team
+    pub roles: Vec<String>,
+}
+
+impl ListItem for Membership {
+    const LIST_KEY: &'static str = "memberships";
+}
@@ -0,0 +1,78 @@
+//! Typed response models for Appwrite API
+
+pub mod attribute;
+pub mod bucket;
+pub mod collection;
+pub mod database;
+pub mod document;
+pub mod execution;
+pub mod file;
+pub mod identity;
+pub mod index;
+pub mod jwt;
+pub mod list;
+pub mod log;
+pub mod membership;
+pub mod session;
+pub mod target;
+pub mod team;
+pub mod token;
+pub mod transaction;
+pub mod user;
+
+pub use attribute::{
+    Attribute, AttributeBoolean, AttributeDatetime, AttributeEmail, AttributeEnum,
+    AttributeFloat, AttributeInteger, AttributeIp, AttributeLine, AttributePoint,
+    AttributePolygon, AttributeRelationship, AttributeString, AttributeUrl,
+};
+pub use bucket::Bucket;
+pub use collection::Collection;
+pub use database::Database;
+pub use document::Document;
+pub use execution::{Execution, Headers};
+pub use file::File;
+pub use identity::Identity;
+pub use index::Index;
+pub use jwt::Jwt;
+pub use list::{List, ListItem};
+pub use log::Log;
+pub use membership::Membership;
+pub use session::Session;
+pub use target::Target;
+pub use team::Team;
+pub use token::Token;
+pub use transaction::Transaction;
+pub use user::{Preferences, User};
+
+/// List of attributes
+pub type AttributeList = List<Attribute>;
+/// List of buckets
+pub type BucketList = List<Bucket>;
+/// List of collections
+pub type CollectionList = List<Collection>;
+/// List of databases
+pub type DatabaseList = List<Database>;
+/// List of documents
+pub type DocumentList<T = serde_json::Map<String, serde_json::Value>> = List<Document<T>>;
+/// List of executions
+pub type ExecutionList = List<Execution>;
+/// List of files
+pub type FileList = List<File>;
+/// List of identities
+pub type IdentityList = List<Identity>;
+/// List of indexes
+pub type IndexList = List<Index>;
+/// List of logs
+pub type LogList = List<Log>;
+/// List of memberships
+pub type MembershipList = List<Membership>;
+/// List of sessions
+pub type SessionList = List<Session>;
+/// List of targets
+pub type TargetList = List<Target>;
+/// List of teams
+pub type TeamList = List<Team>;
+/// List of transactions
+pub type TransactionList = List<Transaction>;
+/// List of users
+pub type UserList = List<User>;
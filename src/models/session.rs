@@ -0,0 +1,75 @@
+//! Session model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+
+/// A user session
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Session {
+    /// Session ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Session creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Session update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// User ID
+    pub user_id: String,
+    /// Session expiration date in ISO 8601 format
+    pub expire: String,
+    /// Session provider
+    pub provider: String,
+    /// Session provider user ID
+    pub provider_uid: String,
+    /// Session provider access token
+    pub provider_access_token: String,
+    /// Session provider access token expiration date in ISO 8601 format
+    pub provider_access_token_expiry: String,
+    /// Session provider refresh token
+    pub provider_refresh_token: String,
+    /// IP address the session was created from
+    pub ip: String,
+    /// Operating system code name
+    pub os_code: String,
+    /// Operating system name
+    pub os_name: String,
+    /// Operating system version
+    pub os_version: String,
+    /// Client type
+    pub client_type: String,
+    /// Client code name
+    pub client_code: String,
+    /// Client name
+    pub client_name: String,
+    /// Client version
+    pub client_version: String,
+    /// Client engine name
+    pub client_engine: String,
+    /// Client engine version
+    pub client_engine_version: String,
+    /// Device name
+    pub device_name: String,
+    /// Device brand name
+    pub device_brand: String,
+    /// Device model name
+    pub device_model: String,
+    /// Country code in ISO 3166-1 two-character format
+    pub country_code: String,
+    /// Country name
+    pub country_name: String,
+    /// Whether this is the session used for the request
+    pub current: bool,
+    /// Authentication factors used for this session
+    pub factors: Vec<String>,
+    /// Secret used to authenticate the user, only returned to server SDKs
+    pub secret: String,
+    /// Most recent date the session passed multi-factor authentication
+    pub mfa_updated_at: String,
+}
+
+impl ListItem for Session {
+    const LIST_KEY: &'static str = "sessions";
+}
@@ -0,0 +1,35 @@
+//! Target model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+
+/// A messaging target of a user, such as an email address or a device
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Target {
+    /// Target ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Target creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Target update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Target name
+    pub name: String,
+    /// User ID
+    pub user_id: String,
+    /// Messaging provider ID
+    pub provider_id: Option<String>,
+    /// Messaging provider type: `email`, `sms` or `push`
+    pub provider_type: String,
+    /// Email address, phone number or push token
+    pub identifier: String,
+    /// Whether the target has expired
+    pub expired: bool,
+}
+
+impl ListItem for Target {
+    const LIST_KEY: &'static str = "targets";
+}
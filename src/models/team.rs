@@ -0,0 +1,29 @@
+//! Team model
+
+use super::{list::ListItem, Preferences};
+use serde::{Deserialize, Serialize};
+
+/// A team of users
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Team {
+    /// Team ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Team creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Team update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Team name
+    pub name: String,
+    /// Total number of team members
+    pub total: u64,
+    /// Team preferences
+    pub prefs: Preferences,
+}
+
+impl ListItem for Team {
+    const LIST_KEY: &'static str = "teams";
+}
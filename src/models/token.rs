@@ -0,0 +1,23 @@
+//! Token model
+
+use serde::{Deserialize, Serialize};
+
+/// A secret token, such as an email verification or password recovery token
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Token {
+    /// Token ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Token creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// User ID
+    pub user_id: String,
+    /// Token secret key, only returned to server SDKs
+    pub secret: String,
+    /// Token expiration date in ISO 8601 format
+    pub expire: String,
+    /// Security phrase of the token
+    pub phrase: String,
+}
@@ -0,0 +1,29 @@
+//! Transaction model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+
+/// A database transaction
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Transaction {
+    /// Transaction ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// Transaction creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// Transaction update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// Transaction status: `pending`, `committing`, `committed`, `rolledBack` or `failed`
+    pub status: String,
+    /// Number of staged operations
+    pub operations: u64,
+    /// Expiration date in ISO 8601 format, after which the transaction is rolled back
+    pub expires_at: String,
+}
+
+impl ListItem for Transaction {
+    const LIST_KEY: &'static str = "transactions";
+}
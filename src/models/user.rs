@@ -0,0 +1,57 @@
+//! User model
+
+use super::list::ListItem;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// User preferences as a key-value object
+pub type Preferences = Map<String, Value>;
+
+/// A user account
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct User {
+    /// User ID
+    #[serde(rename = "$id")]
+    pub id: String,
+    /// User creation date in ISO 8601 format
+    #[serde(rename = "$createdAt")]
+    pub created_at: String,
+    /// User update date in ISO 8601 format
+    #[serde(rename = "$updatedAt")]
+    pub updated_at: String,
+    /// User name
+    pub name: String,
+    /// Hashed user password, only returned to server SDKs
+    pub password: Option<String>,
+    /// Password hashing algorithm
+    pub hash: Option<String>,
+    /// Password hashing algorithm configuration
+    pub hash_options: Option<Value>,
+    /// User registration date in ISO 8601 format
+    pub registration: String,
+    /// Whether the user is enabled
+    pub status: bool,
+    /// Labels assigned to the user
+    pub labels: Vec<String>,
+    /// Password update date in ISO 8601 format
+    pub password_update: String,
+    /// User email address
+    pub email: String,
+    /// User phone number in E.164 format
+    pub phone: String,
+    /// Whether the email address has been verified
+    pub email_verification: bool,
+    /// Whether the phone number has been verified
+    pub phone_verification: bool,
+    /// Whether multi-factor authentication is enabled
+    pub mfa: bool,
+    /// User preferences
+    pub prefs: Preferences,
+    /// Date the user was last active in ISO 8601 format
+    pub accessed_at: String,
+}
+
+impl ListItem for User {
+    const LIST_KEY: &'static str = "users";
+}
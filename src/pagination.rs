@@ -0,0 +1,203 @@
+//! Cursor based pagination over list endpoints
+
+use crate::{
+    error::{AppwriteError, Result},
+    models::{Bucket, Database, Document, File, List, Team, User},
+    query::Query,
+};
+use futures_util::{stream, Stream};
+use std::collections::VecDeque;
+use std::future::Future;
+
+/// Number of items fetched per request when the caller sets no limit
+pub const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// A list item that can be used as a pagination cursor
+pub(crate) trait Cursor {
+    /// ID to pass to `cursorAfter` to get the items following this one
+    fn cursor(&self) -> &str;
+}
+
+impl<T> Cursor for Document<T> {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Cursor for File {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Cursor for User {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Cursor for Database {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Cursor for Bucket {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Cursor for Team {
+    fn cursor(&self) -> &str {
+        &self.id
+    }
+}
+
+struct Pages<T, F> {
+    fetch: F,
+    queries: Vec<Query>,
+    limit: u32,
+    cursor: Option<String>,
+    items: VecDeque<T>,
+    done: bool,
+}
+
+/// Stream every item of a list endpoint, one page at a time
+///
+/// `fetch` requests a page with the given queries. The caller's filters and
+/// orderings are sent with every page, followed by `limit` and `cursorAfter`
+/// queries moving through the results. A caller supplied `limit` sets the
+/// page size, and a `cursorAfter` sets where the stream starts. `offset` and
+/// `cursorBefore` cannot be combined with this pagination and are rejected.
+pub(crate) fn paginate<'a, T, F, Fut>(
+    queries: Option<Vec<Query>>,
+    fetch: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: Cursor + 'a,
+    F: FnMut(Vec<Query>) -> Fut + 'a,
+    Fut: Future<Output = Result<List<T>>> + 'a,
+{
+    let mut pages = Pages {
+        fetch,
+        queries: Vec::new(),
+        limit: DEFAULT_PAGE_SIZE,
+        cursor: None,
+        items: VecDeque::new(),
+        done: false,
+    };
+    let mut error = None;
+
+    for query in queries.unwrap_or_default() {
+        let value = query.values.as_ref().and_then(|values| values.first());
+
+        match query.method.as_str() {
+            "limit" => match value.and_then(|v| v.as_u64()) {
+                Some(limit) if limit > 0 => pages.limit = limit.min(u32::MAX as u64) as u32,
+                _ => error = Some(AppwriteError::invalid_parameter("limit must be a positive number")),
+            },
+            "cursorAfter" => pages.cursor = value.and_then(|v| v.as_str()).map(str::to_string),
+            method @ ("offset" | "cursorBefore") => {
+                error = Some(AppwriteError::invalid_parameter(format!(
+                    "{} queries cannot be used when paginating with a stream",
+                    method
+                )))
+            }
+            _ => pages.queries.push(query),
+        }
+    }
+
+    stream::unfold((pages, error), |(mut pages, error)| async move {
+        if let Some(error) = error {
+            pages.done = true;
+            return Some((Err(error), (pages, None)));
+        }
+
+        loop {
+            if let Some(item) = pages.items.pop_front() {
+                return Some((Ok(item), (pages, None)));
+            }
+            if pages.done {
+                return None;
+            }
+
+            let mut queries = pages.queries.clone();
+            queries.push(Query::limit(pages.limit));
+            if let Some(cursor) = &pages.cursor {
+                queries.push(Query::cursor_after(cursor.as_str()));
+            }
+
+            match (pages.fetch)(queries).await {
+                Ok(page) => {
+                    pages.done = page.items.len() < pages.limit as usize;
+                    match page.items.last() {
+                        Some(last) => pages.cursor = Some(last.cursor().to_string()),
+                        None => pages.done = true,
+                    }
+                    pages.items.extend(page.items);
+                }
+                Err(error) => {
+                    pages.done = true;
+                    return Some((Err(error), (pages, None)));
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use std::sync::{Arc, Mutex};
+
+    fn user(id: &str) -> User {
+        User {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginate_follows_cursor() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+
+        let users: Vec<String> = paginate(
+            Some(vec![Query::equal("status", true), Query::limit(2)]),
+            move |queries: Vec<Query>| {
+                seen.lock().unwrap().push(queries.clone());
+                let page = match queries.len() {
+                    2 => vec![user("a"), user("b")],
+                    _ if queries[2] == Query::cursor_after("b") => vec![user("c")],
+                    _ => vec![],
+                };
+                async move { Ok(List { total: 3, items: page }) }
+            },
+        )
+        .map(|user| user.unwrap().id)
+        .collect()
+        .await;
+
+        assert_eq!(users, vec!["a", "b", "c"]);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1][0], Query::equal("status", true));
+        assert_eq!(requests[1][1], Query::limit(2));
+        assert_eq!(requests[1][2], Query::cursor_after("b"));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_rejects_offset() {
+        let results: Vec<Result<User>> = paginate(Some(vec![Query::offset(10)]), |_| async {
+            Ok(List { total: 1, items: vec![user("a")] })
+        })
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(AppwriteError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,171 @@
+//! Password hashes for importing users
+
+use crate::enums::ShaVersion;
+use serde_json::{Map, Value};
+
+/// A password hash created by another system, used to import users
+///
+/// Imported users sign in with their existing password, and Appwrite keeps
+/// verifying it with the original algorithm.
+///
+/// # Example
+/// ```rust
+/// use appwrite::PasswordHash;
+///
+/// let hash = PasswordHash::Scrypt {
+///     hash: "c2NyeXB0IGhhc2g=".to_string(),
+///     salt: "c2FsdA==".to_string(),
+///     cpu: 16384,
+///     memory: 8,
+///     parallel: 1,
+///     length: 64,
+/// };
+/// assert_eq!(hash.algorithm(), "scrypt");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordHash {
+    /// Argon2 hash in PHC string format
+    Argon2 {
+        /// The hash, such as `$argon2id$v=19$m=...`
+        hash: String,
+    },
+    /// Bcrypt hash
+    Bcrypt {
+        /// The hash, such as `$2a$10$...`
+        hash: String,
+    },
+    /// MD5 hash
+    Md5 {
+        /// The hash
+        hash: String,
+    },
+    /// PHPass hash
+    PhPass {
+        /// The hash
+        hash: String,
+    },
+    /// SHA hash
+    Sha {
+        /// The hash
+        hash: String,
+        /// SHA algorithm. Defaults to SHA-256 on the server
+        version: Option<ShaVersion>,
+    },
+    /// Scrypt hash
+    Scrypt {
+        /// The hash
+        hash: String,
+        /// Salt used to compute the hash
+        salt: String,
+        /// CPU/memory cost (N)
+        cpu: u32,
+        /// Block size (r)
+        memory: u32,
+        /// Parallelization (p)
+        parallel: u32,
+        /// Hash length in bytes
+        length: u32,
+    },
+    /// Scrypt hash modified by Firebase Authentication
+    ScryptModified {
+        /// The hash
+        hash: String,
+        /// Salt used to compute the hash
+        salt: String,
+        /// Salt separator of the Firebase project
+        salt_separator: String,
+        /// Signer key of the Firebase project
+        signer_key: String,
+    },
+}
+
+impl PasswordHash {
+    /// Name of the hashing algorithm, as used in the import endpoint path
+    pub fn algorithm(&self) -> &str {
+        match self {
+            PasswordHash::Argon2 { .. } => "argon2",
+            PasswordHash::Bcrypt { .. } => "bcrypt",
+            PasswordHash::Md5 { .. } => "md5",
+            PasswordHash::PhPass { .. } => "phpass",
+            PasswordHash::Sha { .. } => "sha",
+            PasswordHash::Scrypt { .. } => "scrypt",
+            PasswordHash::ScryptModified { .. } => "scrypt-modified",
+        }
+    }
+
+    /// The hash itself
+    pub fn hash(&self) -> &str {
+        match self {
+            PasswordHash::Argon2 { hash }
+            | PasswordHash::Bcrypt { hash }
+            | PasswordHash::Md5 { hash }
+            | PasswordHash::PhPass { hash }
+            | PasswordHash::Sha { hash, .. }
+            | PasswordHash::Scrypt { hash, .. }
+            | PasswordHash::ScryptModified { hash, .. } => hash,
+        }
+    }
+
+    /// Add the hash and its options to the parameters of an import request
+    pub(crate) fn insert_params(&self, params: &mut Map<String, Value>) {
+        params.insert("password".to_string(), Value::String(self.hash().to_string()));
+
+        match self {
+            PasswordHash::Sha { version: Some(version), .. } => {
+                params.insert("passwordVersion".to_string(), Value::String(version.as_ref().to_string()));
+            }
+            PasswordHash::Scrypt { salt, cpu, memory, parallel, length, .. } => {
+                params.insert("passwordSalt".to_string(), Value::String(salt.clone()));
+                params.insert("passwordCpu".to_string(), Value::Number((*cpu).into()));
+                params.insert("passwordMemory".to_string(), Value::Number((*memory).into()));
+                params.insert("passwordParallel".to_string(), Value::Number((*parallel).into()));
+                params.insert("passwordLength".to_string(), Value::Number((*length).into()));
+            }
+            PasswordHash::ScryptModified { salt, salt_separator, signer_key, .. } => {
+                params.insert("passwordSalt".to_string(), Value::String(salt.clone()));
+                params.insert("passwordSaltSeparator".to_string(), Value::String(salt_separator.clone()));
+                params.insert("passwordSignerKey".to_string(), Value::String(signer_key.clone()));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha_params() {
+        let mut params = Map::new();
+        PasswordHash::Sha {
+            hash: "abc".to_string(),
+            version: Some(ShaVersion::Sha512_256),
+        }
+        .insert_params(&mut params);
+
+        assert_eq!(params["password"], "abc");
+        assert_eq!(params["passwordVersion"], "sha512/256");
+
+        let mut params = Map::new();
+        PasswordHash::Sha { hash: "abc".to_string(), version: None }.insert_params(&mut params);
+        assert!(!params.contains_key("passwordVersion"));
+    }
+
+    #[test]
+    fn test_scrypt_modified_params() {
+        let hash = PasswordHash::ScryptModified {
+            hash: "hash".to_string(),
+            salt: "salt".to_string(),
+            salt_separator: "Bw==".to_string(),
+            signer_key: "key".to_string(),
+        };
+        let mut params = Map::new();
+        hash.insert_params(&mut params);
+
+        assert_eq!(hash.algorithm(), "scrypt-modified");
+        assert_eq!(params.len(), 4);
+        assert_eq!(params["passwordSaltSeparator"], "Bw==");
+        assert_eq!(params["passwordSignerKey"], "key");
+    }
+}
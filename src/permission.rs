@@ -1,78 +1,615 @@
 //! Permission helpers for Appwrite
 
-/// Permission helper functions
-pub struct Permission;
+use crate::error::{AppwriteError, Result};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+/// Maximum length of labels
+const MAX_LABEL_LENGTH: usize = 36;
+
+/// An action a permission grants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PermissionAction {
+    /// Read the resource
+    Read,
+    /// Create resources, such as documents in a collection or files in a bucket
+    Create,
+    /// Update the resource
+    Update,
+    /// Delete the resource
+    Delete,
+    /// Shorthand granting [`Create`](Self::Create), [`Update`](Self::Update)
+    /// and [`Delete`](Self::Delete). It does not grant [`Read`](Self::Read).
+    Write,
+}
+
+impl PermissionAction {
+    /// Actions granted by this action, expanding `write`
+    pub fn expand(self) -> &'static [PermissionAction] {
+        match self {
+            PermissionAction::Read => &[PermissionAction::Read],
+            PermissionAction::Create => &[PermissionAction::Create],
+            PermissionAction::Update => &[PermissionAction::Update],
+            PermissionAction::Delete => &[PermissionAction::Delete],
+            PermissionAction::Write => &[PermissionAction::Create, PermissionAction::Update, PermissionAction::Delete],
+        }
+    }
+}
+
+impl AsRef<str> for PermissionAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            PermissionAction::Read => "read",
+            PermissionAction::Create => "create",
+            PermissionAction::Update => "update",
+            PermissionAction::Delete => "delete",
+            PermissionAction::Write => "write",
+        }
+    }
+}
+
+impl FromStr for PermissionAction {
+    type Err = AppwriteError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "read" => Ok(PermissionAction::Read),
+            "create" => Ok(PermissionAction::Create),
+            "update" => Ok(PermissionAction::Update),
+            "delete" => Ok(PermissionAction::Delete),
+            "write" => Ok(PermissionAction::Write),
+            _ => Err(AppwriteError::invalid_parameter(format!("unknown permission action `{}`", s))),
+        }
+    }
+}
+
+/// A permission granting an action on a resource to a role
+///
+/// Permissions are sent as strings such as `read("any")`. They convert to
+/// and from strings with `Display` and `FromStr` or `TryFrom`, and compare
+/// equal to their string form. Constructors also take roles written as
+/// strings, which are checked before the permission is sent:
+///
+/// ```rust
+/// use appwrite::Permission;
+///
+/// assert_eq!(Permission::read("user:123"), r#"read("user:123")"#);
+/// assert!(Permission::read("user:").validate().is_err());
+///
+/// let permissions: Vec<Permission> = vec![r#"read("any")"#.try_into().unwrap()];
+/// ```
+///
+/// # Example
+/// ```rust
+/// use appwrite::{Permission, Role};
+///
+/// let permission = Permission::update(Role::team_with_role("editors", "owner"));
+/// assert_eq!(permission, r#"update("team:editors/owner")"#);
+/// assert_eq!(r#"update("team:editors/owner")"#.parse::<Permission>().unwrap(), permission);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Permission {
+    /// Action granted
+    pub action: PermissionAction,
+    /// Role the action is granted to
+    pub role: Role,
+}
 
 impl Permission {
+    /// Create a permission granting an action to a role
+    pub fn new(action: PermissionAction, role: impl Into<Role>) -> Self {
+        Self { action, role: role.into() }
+    }
+
     /// Create a read permission for a role
-    pub fn read(role: impl AsRef<str>) -> String {
-        format!(r#"read("{}")"#, role.as_ref())
+    pub fn read(role: impl Into<Role>) -> Self {
+        Self::new(PermissionAction::Read, role)
     }
 
     /// Create a write permission for a role
-    pub fn write(role: impl AsRef<str>) -> String {
-        format!(r#"write("{}")"#, role.as_ref())
+    ///
+    /// Write is a shorthand for create, update and delete permissions.
+    pub fn write(role: impl Into<Role>) -> Self {
+        Self::new(PermissionAction::Write, role)
     }
 
     /// Create a create permission for a role
-    pub fn create(role: impl AsRef<str>) -> String {
-        format!(r#"create("{}")"#, role.as_ref())
+    pub fn create(role: impl Into<Role>) -> Self {
+        Self::new(PermissionAction::Create, role)
     }
 
     /// Create an update permission for a role
-    pub fn update(role: impl AsRef<str>) -> String {
-        format!(r#"update("{}")"#, role.as_ref())
+    pub fn update(role: impl Into<Role>) -> Self {
+        Self::new(PermissionAction::Update, role)
     }
 
     /// Create a delete permission for a role
-    pub fn delete(role: impl AsRef<str>) -> String {
-        format!(r#"delete("{}")"#, role.as_ref())
+    pub fn delete(role: impl Into<Role>) -> Self {
+        Self::new(PermissionAction::Delete, role)
+    }
+
+    /// Permissions equivalent to this one, expanding `write` into create,
+    /// update and delete permissions
+    pub fn expand(&self) -> Vec<Permission> {
+        self.action
+            .expand()
+            .iter()
+            .map(|action| Permission::new(*action, self.role.clone()))
+            .collect()
+    }
+
+    /// Check that the role is well formed
+    pub fn validate(&self) -> Result<()> {
+        self.role.validate()
     }
 }
 
-/// Role helper functions
-pub struct Role;
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, r#"{}("{}")"#, self.action.as_ref(), self.role)
+    }
+}
+
+impl FromStr for Permission {
+    type Err = AppwriteError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (action, role) = split_permission(s)?;
+        Ok(Self::new(action.parse()?, role.parse::<Role>()?))
+    }
+}
+
+/// Split a permission string into its action and role
+fn split_permission(s: &str) -> Result<(&str, &str)> {
+    let invalid = || AppwriteError::invalid_parameter(format!("invalid permission `{}`", s));
+
+    let (action, rest) = s.split_once('(').ok_or_else(invalid)?;
+    let role = rest
+        .strip_suffix(')')
+        .and_then(|role| role.strip_prefix('"'))
+        .and_then(|role| role.strip_suffix('"'))
+        .ok_or_else(invalid)?;
+
+    Ok((action, role))
+}
+
+impl TryFrom<&str> for Permission {
+    type Error = AppwriteError;
+
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for Permission {
+    type Error = AppwriteError;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+/// A role permissions are granted to
+///
+/// Roles are written as strings such as `user:{id}` or `team:{id}/{role}`.
+/// The constructors do not fail: roles are checked with [`Role::validate`]
+/// before they are sent, and when parsed with `FromStr`. Converting a string
+/// with `From` does not fail either, and keeps a malformed role as
+/// [`Role::Invalid`] until it is validated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Role {
+    /// Anyone, signed in or not
+    Any,
+    /// Visitors who are not signed in
+    Guests,
+    /// Any signed in user, optionally only `verified` or `unverified` users
+    Users {
+        /// `verified` or `unverified`
+        status: Option<String>,
+    },
+    /// A user, optionally only while `verified` or `unverified`
+    User {
+        /// User ID
+        id: String,
+        /// `verified` or `unverified`
+        status: Option<String>,
+    },
+    /// Members of a team, optionally only those with a role in the team
+    Team {
+        /// Team ID
+        id: String,
+        /// Role in the team, such as `owner`
+        role: Option<String>,
+    },
+    /// The user of a team membership
+    Member {
+        /// Membership ID
+        id: String,
+    },
+    /// Users with a label
+    Label {
+        /// Label name
+        name: String,
+    },
+    /// A role string that could not be parsed, kept as written
+    ///
+    /// Fails [`Role::validate`], so it is rejected before being sent.
+    Invalid(String),
+}
 
 impl Role {
     /// Any role
-    pub fn any() -> String {
-        "any".to_string()
+    pub fn any() -> Self {
+        Role::Any
     }
 
     /// User role with specific user ID
-    pub fn user(id: impl AsRef<str>) -> String {
-        format!("user:{}", id.as_ref())
+    pub fn user(id: impl Into<String>) -> Self {
+        Role::User { id: id.into(), status: None }
+    }
+
+    /// User role matching only while the user is `verified` or `unverified`
+    pub fn user_with_status(id: impl Into<String>, status: impl Into<String>) -> Self {
+        Role::User { id: id.into(), status: Some(status.into()) }
     }
 
     /// Users role (any authenticated user)
-    pub fn users() -> String {
-        "users".to_string()
+    pub fn users() -> Self {
+        Role::Users { status: None }
+    }
+
+    /// Users role matching only `verified` or `unverified` users
+    pub fn users_with_status(status: impl Into<String>) -> Self {
+        Role::Users { status: Some(status.into()) }
     }
 
     /// Guests role (unauthenticated users)
-    pub fn guests() -> String {
-        "guests".to_string()
+    pub fn guests() -> Self {
+        Role::Guests
     }
 
     /// Team role with specific team ID
-    pub fn team(id: impl AsRef<str>) -> String {
-        format!("team:{}", id.as_ref())
+    pub fn team(id: impl Into<String>) -> Self {
+        Role::Team { id: id.into(), role: None }
     }
 
     /// Team role with specific team ID and role
-    pub fn team_with_role(id: impl AsRef<str>, role: impl AsRef<str>) -> String {
-        format!("team:{}:{}", id.as_ref(), role.as_ref())
+    pub fn team_with_role(id: impl Into<String>, role: impl Into<String>) -> Self {
+        Role::Team { id: id.into(), role: Some(role.into()) }
     }
 
     /// Member role for a team
-    pub fn member(id: impl AsRef<str>) -> String {
-        format!("member:{}", id.as_ref())
+    pub fn member(id: impl Into<String>) -> Self {
+        Role::Member { id: id.into() }
     }
 
     /// Label role
-    pub fn label(name: impl AsRef<str>) -> String {
-        format!("label:{}", name.as_ref())
+    pub fn label(name: impl Into<String>) -> Self {
+        Role::Label { name: name.into() }
+    }
+
+    /// Check that IDs, labels, team roles and statuses are well formed
+    ///
+    /// IDs and team roles have at most 36 characters among `a-z`, `A-Z`,
+    /// `0-9`, `.`, `-` and `_`, and cannot start with a special character.
+    /// Labels are alphanumeric, and statuses are `verified` or `unverified`.
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Role::Any | Role::Guests => Ok(()),
+            Role::Users { status } => validate_status(status.as_deref()),
+            Role::User { id, status } => {
+                validate_id("user", id)?;
+                validate_status(status.as_deref())
+            }
+            Role::Team { id, role } => {
+                validate_id("team", id)?;
+                match role {
+                    Some(role) if !crate::id::is_valid(role) => Err(AppwriteError::invalid_parameter(format!(
+                        "invalid team role `{}`, which must be formed like an ID",
+                        role
+                    ))),
+                    _ => Ok(()),
+                }
+            }
+            Role::Member { id } => validate_id("member", id),
+            Role::Label { name } => {
+                if name.is_empty() || name.len() > MAX_LABEL_LENGTH || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    return Err(AppwriteError::invalid_parameter(format!(
+                        "label `{}` must have 1 to {} alphanumeric characters",
+                        name, MAX_LABEL_LENGTH
+                    )));
+                }
+                Ok(())
+            }
+            Role::Invalid(role) => Err(AppwriteError::invalid_parameter(format!("invalid role `{}`", role))),
+        }
+    }
+}
+
+fn validate_id(kind: &str, id: &str) -> Result<()> {
+    if crate::id::is_valid(id) {
+        Ok(())
+    } else {
+        Err(AppwriteError::invalid_parameter(format!("invalid {} ID `{}` in role", kind, id)))
+    }
+}
+
+fn validate_status(status: Option<&str>) -> Result<()> {
+    match status {
+        None | Some("verified") | Some("unverified") => Ok(()),
+        Some(status) => Err(AppwriteError::invalid_parameter(format!(
+            "role status `{}` must be `verified` or `unverified`",
+            status
+        ))),
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::Any => f.write_str("any"),
+            Role::Guests => f.write_str("guests"),
+            Role::Users { status: None } => f.write_str("users"),
+            Role::Users { status: Some(status) } => write!(f, "users/{}", status),
+            Role::User { id, status: None } => write!(f, "user:{}", id),
+            Role::User { id, status: Some(status) } => write!(f, "user:{}/{}", id, status),
+            Role::Team { id, role: None } => write!(f, "team:{}", id),
+            Role::Team { id, role: Some(role) } => write!(f, "team:{}/{}", id, role),
+            Role::Member { id } => write!(f, "member:{}", id),
+            Role::Label { name } => write!(f, "label:{}", name),
+            Role::Invalid(role) => f.write_str(role),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = AppwriteError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, dimension) = match s.split_once('/') {
+            Some((name, dimension)) => (name, Some(dimension.to_string())),
+            None => (s, None),
+        };
+        let (kind, id) = match name.split_once(':') {
+            Some((kind, id)) => (kind, Some(id.to_string())),
+            None => (name, None),
+        };
+
+        let role = match (kind, id, dimension) {
+            ("any", None, None) => Role::Any,
+            ("guests", None, None) => Role::Guests,
+            ("users", None, status) => Role::Users { status },
+            ("user", Some(id), status) => Role::User { id, status },
+            ("team", Some(id), role) => Role::Team { id, role },
+            ("member", Some(id), None) => Role::Member { id },
+            ("label", Some(name), None) => Role::Label { name },
+            _ => return Err(AppwriteError::invalid_parameter(format!("invalid role `{}`", s))),
+        };
+
+        role.validate()?;
+        Ok(role)
+    }
+}
+
+impl From<&str> for Role {
+    fn from(value: &str) -> Self {
+        value.parse().unwrap_or_else(|_| Role::Invalid(value.to_string()))
+    }
+}
+
+impl From<String> for Role {
+    fn from(value: String) -> Self {
+        value.parse().unwrap_or(Role::Invalid(value))
+    }
+}
+
+macro_rules! impl_string_interop {
+    ($type:ty) => {
+        impl From<$type> for String {
+            fn from(value: $type) -> Self {
+                value.to_string()
+            }
+        }
+
+        // Compares the string form, so values failing validation still compare
+        #[allow(clippy::cmp_owned)]
+        impl PartialEq<str> for $type {
+            fn eq(&self, other: &str) -> bool {
+                self.to_string() == other
+            }
+        }
+
+        impl PartialEq<&str> for $type {
+            fn eq(&self, other: &&str) -> bool {
+                *self == **other
+            }
+        }
+
+        impl PartialEq<String> for $type {
+            fn eq(&self, other: &String) -> bool {
+                *self == *other.as_str()
+            }
+        }
+
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+    };
+}
+
+impl_string_interop!(Permission);
+impl_string_interop!(Role);
+
+// Deserializing keeps roles this SDK rejects as `Role::Invalid`, so a model
+// returned by the server never fails over one of its permissions
+impl<'de> Deserialize<'de> for Permission {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let permission = String::deserialize(deserializer)?;
+        let (action, role) = split_permission(&permission).map_err(de::Error::custom)?;
+        Ok(Self::new(action.parse().map_err(de::Error::custom)?, role))
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+/// Check permissions and convert them to the JSON array sent to the server
+pub(crate) fn permissions_value(permissions: Vec<Permission>) -> Result<Value> {
+    let mut values = Vec::with_capacity(permissions.len());
+    for permission in permissions {
+        permission.validate()?;
+        values.push(Value::String(permission.to_string()));
     }
+
+    Ok(Value::Array(values))
+}
+
+/// Someone permissions are evaluated for, and the roles Appwrite gives them
+///
+/// Mirrors how Appwrite matches roles, to check permissions locally before
+/// making a request or to test permission setups without a server. Requests
+/// made with an API key bypass permissions, and are not modelled here.
+///
+/// # Example
+/// ```rust
+/// use appwrite::{Permission, PermissionAction, Principal, Role};
+///
+/// let permissions = vec![
+///     Permission::read(Role::any()),
+///     Permission::write(Role::team_with_role("editors", "owner")),
+/// ];
+///
+/// let owner = Principal::user("jane").add_team("editors", vec!["owner".to_string()]);
+/// assert!(owner.can(PermissionAction::Update, &permissions));
+///
+/// assert!(Principal::guest().can(PermissionAction::Read, &permissions));
+/// assert!(!Principal::guest().can(PermissionAction::Delete, &permissions));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Principal {
+    user_id: Option<String>,
+    verified: bool,
+    teams: Vec<(String, Vec<String>)>,
+    memberships: Vec<String>,
+    labels: Vec<String>,
+}
+
+impl Principal {
+    /// A visitor who is not signed in
+    pub fn guest() -> Self {
+        Self::default()
+    }
+
+    /// A signed in user, unverified until [`Principal::set_verified`] is called
+    pub fn user(id: impl Into<String>) -> Self {
+        Self {
+            user_id: Some(id.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set whether the user has verified their email or phone
+    pub fn set_verified(mut self, verified: bool) -> Self {
+        self.verified = verified;
+        self
+    }
+
+    /// Add a team the user is a member of, with their roles in the team
+    pub fn add_team(mut self, team_id: impl Into<String>, roles: Vec<String>) -> Self {
+        self.teams.push((team_id.into(), roles));
+        self
+    }
+
+    /// Add a team membership of the user
+    pub fn add_membership(mut self, membership_id: impl Into<String>) -> Self {
+        self.memberships.push(membership_id.into());
+        self
+    }
+
+    /// Add a label of the user
+    pub fn add_label(mut self, label: impl Into<String>) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    /// Every role the principal holds
+    pub fn roles(&self) -> Vec<Role> {
+        let Some(user_id) = &self.user_id else {
+            return vec![Role::any(), Role::guests()];
+        };
+        let status = if self.verified { "verified" } else { "unverified" };
+
+        let mut roles = vec![
+            Role::any(),
+            Role::users(),
+            Role::users_with_status(status),
+            Role::user(user_id.as_str()),
+            Role::user_with_status(user_id.as_str(), status),
+        ];
+        for (team_id, team_roles) in &self.teams {
+            roles.push(Role::team(team_id.as_str()));
+            roles.extend(team_roles.iter().map(|role| Role::team_with_role(team_id.as_str(), role.as_str())));
+        }
+        roles.extend(self.memberships.iter().map(|id| Role::member(id.as_str())));
+        roles.extend(self.labels.iter().map(|label| Role::label(label.as_str())));
+
+        roles
+    }
+
+    /// Whether the principal holds the role
+    pub fn has_role(&self, role: &Role) -> bool {
+        match role {
+            Role::Any => true,
+            Role::Guests => self.user_id.is_none(),
+            Role::Users { status } => self.user_id.is_some() && self.status_matches(status.as_deref()),
+            Role::User { id, status } => {
+                self.user_id.as_deref() == Some(id.as_str()) && self.status_matches(status.as_deref())
+            }
+            Role::Team { id, role } => self.user_id.is_some()
+                && self.teams.iter().any(|(team_id, roles)| {
+                    team_id == id && role.as_ref().map_or(true, |role| roles.contains(role))
+                }),
+            Role::Member { id } => self.user_id.is_some() && self.memberships.contains(id),
+            Role::Label { name } => self.user_id.is_some() && self.labels.contains(name),
+            Role::Invalid(_) => false,
+        }
+    }
+
+    /// Whether the permissions grant the action to the principal
+    ///
+    /// A `write` permission grants create, update and delete, and asking for
+    /// [`PermissionAction::Write`] requires all three. For collections and
+    /// buckets with document or file security, pass the collection's
+    /// permissions followed by the document's: either may grant the action.
+    pub fn can(&self, action: PermissionAction, permissions: &[Permission]) -> bool {
+        action.expand().iter().all(|wanted| {
+            permissions.iter().any(|permission| {
+                permission.action.expand().contains(wanted) && self.has_role(&permission.role)
+            })
+        })
+    }
+
+    fn status_matches(&self, status: Option<&str>) -> bool {
+        match status {
+            None => true,
+            Some("verified") => self.verified,
+            Some("unverified") => !self.verified,
+            Some(_) => false,
+        }
+    }
+}
+
+/// Parse permissions written as strings, checking them like `FromStr`
+pub fn parse_permissions<S: AsRef<str>>(permissions: &[S]) -> Result<Vec<Permission>> {
+    permissions.iter().map(|permission| permission.as_ref().parse()).collect()
 }
 
 #[cfg(test)]
@@ -86,17 +623,116 @@ mod tests {
         assert_eq!(Permission::create("any"), r#"create("any")"#);
         assert_eq!(Permission::update("users"), r#"update("users")"#);
         assert_eq!(Permission::delete("guests"), r#"delete("guests")"#);
+
+        assert_eq!(Permission::read(Role::user("123")), r#"read("user:123")"#);
+        assert_eq!(Permission::write(Role::team("456")), r#"write("team:456")"#);
+        assert_eq!(Permission::create(Role::any()), r#"create("any")"#);
+        assert_eq!(Permission::update(Role::users()), r#"update("users")"#);
+        assert_eq!(Permission::delete(Role::guests()), r#"delete("guests")"#);
     }
 
     #[test]
     fn test_roles() {
         assert_eq!(Role::any(), "any");
         assert_eq!(Role::user("123"), "user:123");
+        assert_eq!(Role::user_with_status("123", "verified"), "user:123/verified");
         assert_eq!(Role::users(), "users");
+        assert_eq!(Role::users_with_status("unverified"), "users/unverified");
         assert_eq!(Role::guests(), "guests");
         assert_eq!(Role::team("456"), "team:456");
-        assert_eq!(Role::team_with_role("456", "admin"), "team:456:admin");
+        assert_eq!(Role::team_with_role("456", "admin"), "team:456/admin");
         assert_eq!(Role::member("789"), "member:789");
         assert_eq!(Role::label("vip"), "label:vip");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_round_trip() {
+        for permission in [
+            r#"read("any")"#,
+            r#"write("users/verified")"#,
+            r#"update("user:abc/unverified")"#,
+            r#"delete("team:t1/owner")"#,
+            r#"create("member:m1")"#,
+            r#"read("label:vip")"#,
+        ] {
+            assert_eq!(permission.parse::<Permission>().unwrap().to_string(), permission);
+        }
+    }
+
+    #[test]
+    fn test_invalid_roles() {
+        assert!(Role::user("").validate().is_err());
+        assert!(Role::user_with_status("123", "banned").validate().is_err());
+        assert!(Role::team_with_role("456", "").validate().is_err());
+        assert!(Role::team_with_role("456", "-lead").validate().is_err());
+        assert!(Role::team_with_role("456", "project-manager").validate().is_ok());
+        assert!("team:456/billing_admin".parse::<Role>().is_ok());
+        assert_eq!(Role::from("admins"), Role::Invalid("admins".to_string()));
+        assert!(Permission::read("admins").validate().is_err());
+        assert!(Permission::try_from(r#"read("admins")"#).is_err());
+        assert!(Role::label("not-alphanumeric").validate().is_err());
+        assert!("team:x:y:z".parse::<Role>().is_err());
+        assert!("admins".parse::<Role>().is_err());
+        assert!(r#"own("any")"#.parse::<Permission>().is_err());
+        assert!(r#"read(any)"#.parse::<Permission>().is_err());
+        assert!(permissions_value(vec![Permission::read(Role::user("_bad"))]).is_err());
+    }
+
+    #[test]
+    fn test_principal_roles() {
+        let guest = Principal::guest();
+        assert_eq!(guest.roles(), vec![Role::any(), Role::guests()]);
+        assert!(!guest.has_role(&Role::users()));
+
+        let user = Principal::user("jane")
+            .set_verified(true)
+            .add_team("editors", vec!["owner".to_string()])
+            .add_membership("m1")
+            .add_label("vip");
+        for role in user.roles() {
+            assert!(user.has_role(&role), "{} should be held", role);
+        }
+        assert!(!user.has_role(&Role::guests()));
+        assert!(!user.has_role(&Role::users_with_status("unverified")));
+        assert!(!user.has_role(&Role::user("john")));
+        assert!(!user.has_role(&Role::team_with_role("editors", "admin")));
+        assert!(!user.has_role(&Role::team("writers")));
+    }
+
+    #[test]
+    fn test_principal_can() {
+        let permissions = parse_permissions(&[
+            r#"read("users")"#,
+            r#"write("user:jane/verified")"#,
+            r#"delete("team:editors/owner")"#,
+        ])
+        .unwrap();
+
+        let jane = Principal::user("jane");
+        assert!(jane.can(PermissionAction::Read, &permissions));
+        assert!(!jane.can(PermissionAction::Update, &permissions));
+
+        let jane = jane.set_verified(true);
+        assert!(jane.can(PermissionAction::Update, &permissions));
+        assert!(jane.can(PermissionAction::Write, &permissions));
+
+        let owner = Principal::user("john").add_team("editors", vec!["owner".to_string()]);
+        assert!(owner.can(PermissionAction::Delete, &permissions));
+        assert!(!owner.can(PermissionAction::Write, &permissions));
+        assert!(!Principal::guest().can(PermissionAction::Read, &permissions));
+        assert!(!Principal::guest().can(PermissionAction::Read, &[]));
+    }
+
+    #[test]
+    fn test_write_expands() {
+        let expanded = Permission::write(Role::users()).expand();
+        assert_eq!(
+            expanded,
+            vec![
+                Permission::create(Role::users()),
+                Permission::update(Role::users()),
+                Permission::delete(Role::users()),
+            ]
+        );
+    }
+}
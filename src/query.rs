@@ -1,19 +1,106 @@
 //! Query builder for database operations
 
-use serde::{Deserialize, Serialize};
+use crate::error::AppwriteError;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
+use std::ops::{BitAnd, BitOr};
+use std::str::FromStr;
 
 /// Query builder for Appwrite database operations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Queries are built with the associated functions and converted to the JSON
+/// string Appwrite expects when a request is sent. `&` and `|` combine
+/// queries into `and` and `or` queries, and `FromStr` parses the JSON string
+/// back:
+///
+/// ```rust
+/// use appwrite::Query;
+///
+/// let query = Query::equal("status", "active") & (Query::greater_than("age", 18) | Query::is_null("age"));
+/// assert_eq!(query.method, "and");
+/// assert_eq!(query.queries.len(), 2);
+///
+/// let parsed: Query = query.to_string().parse().unwrap();
+/// assert_eq!(parsed, query);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
 pub struct Query {
     /// Query method
     pub method: String,
     /// Attribute name (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub attribute: Option<String>,
     /// Query values (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub values: Option<Vec<Value>>,
+    /// Nested queries of an `and` or `or` query
+    pub queries: Vec<Query>,
+}
+
+/// Query as received from the server, with nested queries in `values`
+#[derive(Deserialize)]
+struct WireQuery {
+    method: String,
+    #[serde(default)]
+    attribute: Option<String>,
+    #[serde(default)]
+    values: Option<Vec<Value>>,
+}
+
+/// Query as sent to the server, with nested queries in `values`
+#[derive(Serialize)]
+struct WireQueryRef<'a> {
+    method: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attribute: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    values: Option<WireValues<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum WireValues<'a> {
+    Values(&'a [Value]),
+    Queries(&'a [Query]),
+}
+
+/// A date and time accepted by the `$createdAt` and `$updatedAt` filters
+///
+/// Implemented for RFC 3339 strings, and for `chrono::DateTime` and
+/// `time::OffsetDateTime` with the `chrono` and `time` features.
+pub trait QueryDateTime {
+    /// Format the date and time as RFC 3339
+    fn to_rfc3339(&self) -> String;
+}
+
+impl QueryDateTime for &str {
+    fn to_rfc3339(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl QueryDateTime for String {
+    fn to_rfc3339(&self) -> String {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> QueryDateTime for chrono::DateTime<Tz>
+where
+    Tz::Offset: std::fmt::Display,
+{
+    fn to_rfc3339(&self) -> String {
+        chrono::DateTime::to_rfc3339(self)
+    }
+}
+
+#[cfg(feature = "time")]
+impl QueryDateTime for time::OffsetDateTime {
+    /// Dates that RFC 3339 cannot represent, such as years after 9999,
+    /// format as an empty string and are rejected by the server
+    fn to_rfc3339(&self) -> String {
+        self.format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_default()
+    }
 }
 
 impl Query {
@@ -23,15 +110,15 @@ impl Query {
             method: method.into(),
             attribute: None,
             values: None,
+            queries: Vec::new(),
         }
     }
 
     /// Create a new query with attribute
     pub fn with_attribute(method: impl Into<String>, attribute: impl Into<String>) -> Self {
         Self {
-            method: method.into(),
             attribute: Some(attribute.into()),
-            values: None,
+            ..Self::new(method)
         }
     }
 
@@ -42,9 +129,9 @@ impl Query {
         values: Vec<T>,
     ) -> Self {
         Self {
-            method: method.into(),
             attribute: Some(attribute.into()),
             values: Some(values.into_iter().map(|v| v.into()).collect()),
+            ..Self::new(method)
         }
     }
 
@@ -54,191 +141,448 @@ impl Query {
         attribute: impl Into<String>,
         value: T,
     ) -> Self {
+        Self::with_values(method, attribute, vec![value])
+    }
+
+    /// Create a new query with values and no attribute
+    fn without_attribute(method: impl Into<String>, values: Vec<Value>) -> Self {
         Self {
-            method: method.into(),
-            attribute: Some(attribute.into()),
-            values: Some(vec![value.into()]),
+            values: Some(values),
+            ..Self::new(method)
         }
     }
 
+    /// Whether this query combines nested queries
+    pub fn is_logical(&self) -> bool {
+        self.method == "and" || self.method == "or"
+    }
+
     /// Equal query
-    pub fn equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> String {
-        serde_json::to_string(&Self::with_value("equal", attribute, value))
-            .unwrap_or_default()
+    pub fn equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("equal", attribute, value)
+    }
+
+    /// Equal to any of the values, like SQL `IN`
+    pub fn equal_any<T: Into<Value>>(attribute: impl Into<String>, values: Vec<T>) -> Self {
+        Self::with_values("equal", attribute, values)
     }
 
     /// Not equal query
-    pub fn not_equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> String {
-        serde_json::to_string(&Self::with_value("notEqual", attribute, value))
-            .unwrap_or_default()
+    pub fn not_equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("notEqual", attribute, value)
     }
 
     /// Less than query
-    pub fn less_than<T: Into<Value>>(attribute: impl Into<String>, value: T) -> String {
-        serde_json::to_string(&Self::with_value("lessThan", attribute, value))
-            .unwrap_or_default()
+    pub fn less_than<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("lessThan", attribute, value)
     }
 
     /// Less than or equal query
-    pub fn less_than_equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> String {
-        serde_json::to_string(&Self::with_value("lessThanEqual", attribute, value))
-            .unwrap_or_default()
+    pub fn less_than_equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("lessThanEqual", attribute, value)
     }
 
     /// Greater than query
-    pub fn greater_than<T: Into<Value>>(attribute: impl Into<String>, value: T) -> String {
-        serde_json::to_string(&Self::with_value("greaterThan", attribute, value))
-            .unwrap_or_default()
+    pub fn greater_than<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("greaterThan", attribute, value)
     }
 
     /// Greater than or equal query
-    pub fn greater_than_equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> String {
-        serde_json::to_string(&Self::with_value("greaterThanEqual", attribute, value))
-            .unwrap_or_default()
+    pub fn greater_than_equal<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("greaterThanEqual", attribute, value)
     }
 
     /// Is null query
-    pub fn is_null(attribute: impl Into<String>) -> String {
-        serde_json::to_string(&Self::with_attribute("isNull", attribute))
-            .unwrap_or_default()
+    pub fn is_null(attribute: impl Into<String>) -> Self {
+        Self::with_attribute("isNull", attribute)
     }
 
     /// Is not null query
-    pub fn is_not_null(attribute: impl Into<String>) -> String {
-        serde_json::to_string(&Self::with_attribute("isNotNull", attribute))
-            .unwrap_or_default()
+    pub fn is_not_null(attribute: impl Into<String>) -> Self {
+        Self::with_attribute("isNotNull", attribute)
     }
 
     /// Between query
-    pub fn between<T: Into<Value>>(attribute: impl Into<String>, start: T, end: T) -> String {
-        serde_json::to_string(&Self::with_values(
-            "between",
-            attribute,
-            vec![start.into(), end.into()],
-        ))
-        .unwrap_or_default()
+    pub fn between<T: Into<Value>>(attribute: impl Into<String>, start: T, end: T) -> Self {
+        Self::with_values("between", attribute, vec![start.into(), end.into()])
+    }
+
+    /// Not between query
+    pub fn not_between<T: Into<Value>>(attribute: impl Into<String>, start: T, end: T) -> Self {
+        Self::with_values("notBetween", attribute, vec![start.into(), end.into()])
     }
 
     /// Starts with query
-    pub fn starts_with(attribute: impl Into<String>, value: impl Into<String>) -> String {
-        serde_json::to_string(&Self::with_value("startsWith", attribute, value.into()))
-            .unwrap_or_default()
+    pub fn starts_with(attribute: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::with_value("startsWith", attribute, value.into())
     }
 
     /// Ends with query
-    pub fn ends_with(attribute: impl Into<String>, value: impl Into<String>) -> String {
-        serde_json::to_string(&Self::with_value("endsWith", attribute, value.into()))
-            .unwrap_or_default()
+    pub fn ends_with(attribute: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::with_value("endsWith", attribute, value.into())
+    }
+
+    /// Not starts with query
+    pub fn not_starts_with(attribute: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::with_value("notStartsWith", attribute, value.into())
+    }
+
+    /// Not ends with query
+    pub fn not_ends_with(attribute: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::with_value("notEndsWith", attribute, value.into())
     }
 
     /// Select query
-    pub fn select(attributes: Vec<String>) -> String {
-        let values: Vec<Value> = attributes.into_iter().map(Value::String).collect();
-        serde_json::to_string(&Query {
-            method: "select".to_string(),
-            attribute: None,
-            values: Some(values),
-        })
-        .unwrap_or_default()
+    pub fn select(attributes: Vec<String>) -> Self {
+        Self::without_attribute("select", attributes.into_iter().map(Value::String).collect())
     }
 
     /// Search query
-    pub fn search(attribute: impl Into<String>, value: impl Into<String>) -> String {
-        serde_json::to_string(&Self::with_value("search", attribute, value.into()))
-            .unwrap_or_default()
+    pub fn search(attribute: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::with_value("search", attribute, value.into())
+    }
+
+    /// Not search query
+    pub fn not_search(attribute: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::with_value("notSearch", attribute, value.into())
     }
 
     /// Order ascending query
-    pub fn order_asc(attribute: impl Into<String>) -> String {
-        serde_json::to_string(&Self::with_attribute("orderAsc", attribute))
-            .unwrap_or_default()
+    pub fn order_asc(attribute: impl Into<String>) -> Self {
+        Self::with_attribute("orderAsc", attribute)
     }
 
     /// Order descending query
-    pub fn order_desc(attribute: impl Into<String>) -> String {
-        serde_json::to_string(&Self::with_attribute("orderDesc", attribute))
-            .unwrap_or_default()
+    pub fn order_desc(attribute: impl Into<String>) -> Self {
+        Self::with_attribute("orderDesc", attribute)
+    }
+
+    /// Order randomly query
+    pub fn order_random() -> Self {
+        Self::new("orderRandom")
     }
 
     /// Cursor before query
-    pub fn cursor_before(id: impl Into<String>) -> String {
-        serde_json::to_string(&Query {
-            method: "cursorBefore".to_string(),
-            attribute: None,
-            values: Some(vec![Value::String(id.into())]),
-        })
-        .unwrap_or_default()
+    pub fn cursor_before(id: impl Into<String>) -> Self {
+        Self::without_attribute("cursorBefore", vec![Value::String(id.into())])
     }
 
     /// Cursor after query
-    pub fn cursor_after(id: impl Into<String>) -> String {
-        serde_json::to_string(&Query {
-            method: "cursorAfter".to_string(),
-            attribute: None,
-            values: Some(vec![Value::String(id.into())]),
-        })
-        .unwrap_or_default()
+    pub fn cursor_after(id: impl Into<String>) -> Self {
+        Self::without_attribute("cursorAfter", vec![Value::String(id.into())])
     }
 
     /// Limit query
-    pub fn limit(limit: u32) -> String {
-        serde_json::to_string(&Query {
-            method: "limit".to_string(),
-            attribute: None,
-            values: Some(vec![Value::Number(limit.into())]),
-        })
-        .unwrap_or_default()
+    pub fn limit(limit: u32) -> Self {
+        Self::without_attribute("limit", vec![Value::Number(limit.into())])
     }
 
     /// Offset query
-    pub fn offset(offset: u32) -> String {
-        serde_json::to_string(&Query {
-            method: "offset".to_string(),
-            attribute: None,
-            values: Some(vec![Value::Number(offset.into())]),
-        })
-        .unwrap_or_default()
+    pub fn offset(offset: u32) -> Self {
+        Self::without_attribute("offset", vec![Value::Number(offset.into())])
     }
 
     /// Contains query
-    pub fn contains<T: Into<Value>>(attribute: impl Into<String>, value: T) -> String {
-        serde_json::to_string(&Self::with_value("contains", attribute, value))
-            .unwrap_or_default()
+    ///
+    /// Matches array attributes containing the value, string attributes
+    /// containing the substring, and spatial attributes containing the
+    /// [`Point`](crate::Point), [`Line`](crate::Line) or [`Polygon`](crate::Polygon).
+    pub fn contains<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("contains", attribute, value)
+    }
+
+    /// Not contains query
+    pub fn not_contains<T: Into<Value>>(attribute: impl Into<String>, value: T) -> Self {
+        Self::with_value("notContains", attribute, value)
+    }
+
+    /// Distance queries compare spatial attributes to a point, line or
+    /// polygon. `distance` is in meters when `meters` is true, and in the
+    /// units of the coordinates otherwise.
+    fn distance<G: Into<Value>>(method: &str, attribute: impl Into<String>, geometry: G, distance: f64, meters: bool) -> Self {
+        Self::with_value(method, attribute, vec![geometry.into(), Value::from(distance), Value::Bool(meters)])
+    }
+
+    /// At exactly `distance` from the geometry query
+    pub fn distance_equal<G: Into<Value>>(attribute: impl Into<String>, geometry: G, distance: f64, meters: bool) -> Self {
+        Self::distance("distanceEqual", attribute, geometry, distance, meters)
+    }
+
+    /// Not at exactly `distance` from the geometry query
+    pub fn distance_not_equal<G: Into<Value>>(attribute: impl Into<String>, geometry: G, distance: f64, meters: bool) -> Self {
+        Self::distance("distanceNotEqual", attribute, geometry, distance, meters)
+    }
+
+    /// Further than `distance` from the geometry query
+    pub fn distance_greater_than<G: Into<Value>>(attribute: impl Into<String>, geometry: G, distance: f64, meters: bool) -> Self {
+        Self::distance("distanceGreaterThan", attribute, geometry, distance, meters)
+    }
+
+    /// Closer than `distance` to the geometry query
+    pub fn distance_less_than<G: Into<Value>>(attribute: impl Into<String>, geometry: G, distance: f64, meters: bool) -> Self {
+        Self::distance("distanceLessThan", attribute, geometry, distance, meters)
+    }
+
+    /// Intersects the geometry query
+    pub fn intersects<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("intersects", attribute, geometry)
+    }
+
+    /// Does not intersect the geometry query
+    pub fn not_intersects<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("notIntersects", attribute, geometry)
+    }
+
+    /// Crosses the geometry query
+    pub fn crosses<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("crosses", attribute, geometry)
+    }
+
+    /// Does not cross the geometry query
+    pub fn not_crosses<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("notCrosses", attribute, geometry)
+    }
+
+    /// Overlaps the geometry query
+    pub fn overlaps<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("overlaps", attribute, geometry)
+    }
+
+    /// Does not overlap the geometry query
+    pub fn not_overlaps<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("notOverlaps", attribute, geometry)
+    }
+
+    /// Touches the geometry query
+    pub fn touches<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("touches", attribute, geometry)
+    }
+
+    /// Does not touch the geometry query
+    pub fn not_touches<G: Into<Value>>(attribute: impl Into<String>, geometry: G) -> Self {
+        Self::with_value("notTouches", attribute, geometry)
+    }
+
+    /// Created before the given date query
+    pub fn created_before(value: impl QueryDateTime) -> Self {
+        Self::less_than("$createdAt", value.to_rfc3339())
+    }
+
+    /// Created after the given date query
+    pub fn created_after(value: impl QueryDateTime) -> Self {
+        Self::greater_than("$createdAt", value.to_rfc3339())
+    }
+
+    /// Updated before the given date query
+    pub fn updated_before(value: impl QueryDateTime) -> Self {
+        Self::less_than("$updatedAt", value.to_rfc3339())
+    }
+
+    /// Updated after the given date query
+    pub fn updated_after(value: impl QueryDateTime) -> Self {
+        Self::greater_than("$updatedAt", value.to_rfc3339())
     }
 
     /// OR queries
-    pub fn or_queries(queries: Vec<String>) -> String {
-        let parsed_queries: Vec<Value> = queries
-            .into_iter()
-            .filter_map(|q| serde_json::from_str(&q).ok())
-            .collect();
-
-        serde_json::to_string(&Query {
-            method: "or".to_string(),
-            attribute: None,
-            values: Some(parsed_queries),
-        })
-        .unwrap_or_default()
+    pub fn or_queries(queries: Vec<Query>) -> Self {
+        Self {
+            queries,
+            ..Self::new("or")
+        }
     }
 
     /// AND queries
-    pub fn and_queries(queries: Vec<String>) -> String {
-        let parsed_queries: Vec<Value> = queries
-            .into_iter()
-            .filter_map(|q| serde_json::from_str(&q).ok())
-            .collect();
-
-        serde_json::to_string(&Query {
-            method: "and".to_string(),
-            attribute: None,
-            values: Some(parsed_queries),
-        })
-        .unwrap_or_default()
+    pub fn and_queries(queries: Vec<Query>) -> Self {
+        Self {
+            queries,
+            ..Self::new("and")
+        }
+    }
+
+    /// Combine two queries with a logical method, merging queries already using it
+    fn combine(method: &str, left: Query, right: Query) -> Self {
+        let mut queries = Vec::new();
+        for query in [left, right] {
+            if query.method == method {
+                queries.extend(query.queries);
+            } else {
+                queries.push(query);
+            }
+        }
+
+        Self {
+            queries,
+            ..Self::new(method)
+        }
     }
 }
 
-impl ToString for Query {
-    fn to_string(&self) -> String {
-        serde_json::to_string(self).unwrap_or_default()
+impl BitAnd for Query {
+    type Output = Query;
+
+    fn bitand(self, rhs: Query) -> Query {
+        Query::combine("and", self, rhs)
+    }
+}
+
+impl BitOr for Query {
+    type Output = Query;
+
+    fn bitor(self, rhs: Query) -> Query {
+        Query::combine("or", self, rhs)
     }
-}
\ No newline at end of file
+}
+
+impl Serialize for Query {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let values = if self.is_logical() {
+            Some(WireValues::Queries(&self.queries))
+        } else {
+            self.values.as_deref().map(WireValues::Values)
+        };
+
+        WireQueryRef {
+            method: &self.method,
+            attribute: self.attribute.as_deref(),
+            values,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Query {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let wire = WireQuery::deserialize(deserializer)?;
+        let mut query = Query {
+            attribute: wire.attribute,
+            ..Query::new(wire.method)
+        };
+
+        if query.is_logical() {
+            query.queries = wire
+                .values
+                .unwrap_or_default()
+                .into_iter()
+                .map(serde_json::from_value)
+                .collect::<Result<Vec<Query>, _>>()
+                .map_err(de::Error::custom)?;
+        } else {
+            query.values = wire.values;
+        }
+
+        Ok(query)
+    }
+}
+
+impl FromStr for Query {
+    type Err = AppwriteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wire_format() {
+        assert_eq!(
+            Query::equal("status", "active").to_string(),
+            r#"{"method":"equal","attribute":"status","values":["active"]}"#
+        );
+        assert_eq!(Query::limit(10).to_string(), r#"{"method":"limit","values":[10]}"#);
+        assert_eq!(
+            Query::or_queries(vec![Query::is_null("a"), Query::equal("b", 1)]).to_string(),
+            r#"{"method":"or","values":[{"method":"isNull","attribute":"a"},{"method":"equal","attribute":"b","values":[1]}]}"#
+        );
+    }
+
+    #[test]
+    fn test_negated_and_time_queries() {
+        assert_eq!(
+            Query::equal_any("status", vec!["active", "pending"]).to_string(),
+            r#"{"method":"equal","attribute":"status","values":["active","pending"]}"#
+        );
+        assert_eq!(
+            Query::not_between("age", 18, 65).to_string(),
+            r#"{"method":"notBetween","attribute":"age","values":[18,65]}"#
+        );
+        assert_eq!(Query::not_contains("tags", "draft").method, "notContains");
+        assert_eq!(Query::order_random().to_string(), r#"{"method":"orderRandom"}"#);
+        assert_eq!(
+            Query::created_after("2024-01-01T00:00:00Z").to_string(),
+            r#"{"method":"greaterThan","attribute":"$createdAt","values":["2024-01-01T00:00:00Z"]}"#
+        );
+        assert_eq!(Query::updated_before("2024-01-01".to_string()).attribute.as_deref(), Some("$updatedAt"));
+    }
+
+    #[test]
+    fn test_spatial_queries() {
+        use crate::geometry::{Point, Polygon};
+
+        assert_eq!(
+            Query::distance_less_than("location", Point::new(2.5, 48.5), 1000.0, true).to_string(),
+            r#"{"method":"distanceLessThan","attribute":"location","values":[[[2.5,48.5],1000.0,true]]}"#
+        );
+
+        let zone = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0)]);
+        assert_eq!(
+            Query::intersects("area", zone).to_string(),
+            r#"{"method":"intersects","attribute":"area","values":[[[[0.0,0.0],[1.0,0.0],[1.0,1.0],[0.0,0.0]]]]}"#
+        );
+        assert_eq!(
+            Query::contains("area", Point::new(0.5, 0.5)).values,
+            Some(vec![serde_json::json!([0.5, 0.5])])
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_datetime() {
+        let date = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:00+02:00").unwrap();
+        assert_eq!(
+            Query::created_before(date).values,
+            Some(vec![Value::String("2024-05-01T12:30:00+02:00".to_string())])
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_datetime() {
+        let date = time::OffsetDateTime::from_unix_timestamp(0).unwrap();
+        assert_eq!(
+            Query::updated_after(date).values,
+            Some(vec![Value::String("1970-01-01T00:00:00Z".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_operators_merge_nested_queries() {
+        let query = Query::equal("a", 1) & Query::equal("b", 2) & (Query::equal("c", 3) | Query::equal("d", 4));
+
+        assert_eq!(query.method, "and");
+        assert_eq!(query.queries.len(), 3);
+        assert_eq!(query.queries[2].method, "or");
+        assert_eq!(query.queries[2].queries, vec![Query::equal("c", 3), Query::equal("d", 4)]);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let query = Query::and_queries(vec![
+            Query::between("age", 18, 65),
+            Query::starts_with("name", "J") | Query::select(vec!["name".to_string()]),
+        ]);
+
+        assert_eq!(query.to_string().parse::<Query>().unwrap(), query);
+        assert!(r#"{"method":"or","values":["not a query"]}"#.parse::<Query>().is_err());
+        assert!("equal".parse::<Query>().is_err());
+    }
+}
@@ -0,0 +1,370 @@
+//! Client side validation of queries against a collection schema
+
+use crate::{
+    enums::IndexType,
+    error::{AppwriteError, Result},
+    models::{Attribute, Collection, Index},
+    query::Query,
+};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+/// A problem found in a query by [`QueryValidator`]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum QueryError {
+    /// The query names an attribute the collection does not have
+    #[error("unknown attribute `{attribute}` in `{method}` query")]
+    UnknownAttribute {
+        /// Query method
+        method: String,
+        /// Attribute name
+        attribute: String,
+    },
+
+    /// The query method cannot be used on the attribute's type
+    #[error("`{method}` query cannot be used on {attribute_type} attribute `{attribute}`")]
+    TypeMismatch {
+        /// Query method
+        method: String,
+        /// Attribute name
+        attribute: String,
+        /// Attribute type, such as `integer`
+        attribute_type: String,
+    },
+
+    /// A query value does not match the attribute's type
+    #[error("`{method}` query on {attribute_type} attribute `{attribute}` has invalid value {value}")]
+    InvalidValue {
+        /// Query method
+        method: String,
+        /// Attribute name
+        attribute: String,
+        /// Attribute type, such as `integer`
+        attribute_type: String,
+        /// The invalid value
+        value: Value,
+    },
+
+    /// A `search` or `notSearch` query on an attribute without a fulltext index
+    #[error("`{method}` query on `{attribute}` requires a fulltext index")]
+    MissingFulltextIndex {
+        /// Query method
+        method: String,
+        /// Attribute name
+        attribute: String,
+    },
+
+    /// More than one `cursorAfter` or `cursorBefore` query
+    #[error("only one cursorAfter or cursorBefore query can be used")]
+    ConflictingCursors,
+}
+
+/// Known type of an attribute
+#[derive(Debug, Clone)]
+struct Field {
+    attribute_type: String,
+    array: bool,
+}
+
+/// Checks queries against a collection schema before they are sent
+///
+/// Mistakes the server would answer with a generic `400` are reported as
+/// [`QueryError`]s: unknown attributes, methods that do not apply to an
+/// attribute's type, values of the wrong type, searches without a fulltext
+/// index and conflicting cursors. Queries using methods the validator does
+/// not know are left to the server.
+///
+/// # Example
+/// ```rust
+/// use appwrite::{AppwriteError, Query, QueryError, QueryValidator};
+/// use appwrite::models::{Attribute, AttributeInteger};
+///
+/// let validator = QueryValidator::new(
+///     vec![Attribute::Integer(AttributeInteger {
+///         key: "age".to_string(),
+///         attribute_type: "integer".to_string(),
+///         ..Default::default()
+///     })],
+///     vec![],
+/// );
+///
+/// assert!(validator.validate(&[Query::greater_than("age", 18)]).is_ok());
+///
+/// match validator.validate(&[Query::equal("aeg", 18)]) {
+///     Err(AppwriteError::InvalidQuery(errors)) => {
+///         assert!(matches!(&errors[0], QueryError::UnknownAttribute { attribute, .. } if attribute == "aeg"));
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueryValidator {
+    fields: HashMap<String, Field>,
+    fulltext: HashSet<String>,
+}
+
+impl QueryValidator {
+    /// Create a validator from a collection's attributes and indexes
+    pub fn new(attributes: Vec<Attribute>, indexes: Vec<Index>) -> Self {
+        let mut fields = HashMap::new();
+        for (key, attribute_type) in [
+            ("$id", "string"),
+            ("$sequence", "integer"),
+            ("$createdAt", "datetime"),
+            ("$updatedAt", "datetime"),
+        ] {
+            fields.insert(
+                key.to_string(),
+                Field { attribute_type: attribute_type.to_string(), array: false },
+            );
+        }
+        for attribute in &attributes {
+            fields.insert(
+                attribute.key().to_string(),
+                Field { attribute_type: attribute.attribute_type().to_string(), array: attribute.array() },
+            );
+        }
+
+        let fulltext = indexes
+            .into_iter()
+            .filter(|index| index.index_type == IndexType::Fulltext)
+            .flat_map(|index| index.attributes)
+            .collect();
+
+        Self { fields, fulltext }
+    }
+
+    /// Create a validator from a collection, as returned by
+    /// [`Databases::get_collection`](crate::Databases::get_collection)
+    pub fn from_collection(collection: &Collection) -> Self {
+        Self::new(collection.attributes.clone(), collection.indexes.clone())
+    }
+
+    /// Check the queries, returning every problem found as
+    /// [`AppwriteError::InvalidQuery`]
+    pub fn validate(&self, queries: &[Query]) -> Result<()> {
+        let mut errors = Vec::new();
+        let mut cursors = 0;
+
+        for query in queries {
+            if query.method == "cursorAfter" || query.method == "cursorBefore" {
+                cursors += 1;
+            }
+            self.check(query, &mut errors);
+        }
+        if cursors > 1 {
+            errors.push(QueryError::ConflictingCursors);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppwriteError::InvalidQuery(errors))
+        }
+    }
+
+    fn check(&self, query: &Query, errors: &mut Vec<QueryError>) {
+        let method = query.method.as_str();
+
+        if query.is_logical() {
+            for nested in &query.queries {
+                self.check(nested, errors);
+            }
+            return;
+        }
+
+        if method == "select" {
+            for value in query.values.iter().flatten() {
+                if let Some(attribute) = value.as_str() {
+                    if attribute != "*" && self.field(attribute).is_none() {
+                        errors.push(unknown_attribute(method, attribute));
+                    }
+                }
+            }
+            return;
+        }
+
+        let Some(attribute) = query.attribute.as_deref() else {
+            return;
+        };
+        let Some(field) = self.field(attribute) else {
+            errors.push(unknown_attribute(method, attribute));
+            return;
+        };
+        // Queries on the attributes of related documents are checked by the server
+        if field.attribute_type == "relationship" && attribute.contains('.') {
+            return;
+        }
+
+        let attribute_type = field.attribute_type.as_str();
+        let is_string = attribute_type == "string";
+        let is_spatial = matches!(attribute_type, "point" | "linestring" | "polygon");
+
+        let applies = match method {
+            "equal" | "notEqual" | "isNull" | "isNotNull" | "orderAsc" | "orderDesc" => true,
+            "lessThan" | "lessThanEqual" | "greaterThan" | "greaterThanEqual" | "between" | "notBetween" => {
+                !matches!(attribute_type, "boolean" | "relationship") && !is_spatial
+            }
+            "startsWith" | "endsWith" | "notStartsWith" | "notEndsWith" | "search" | "notSearch" => is_string,
+            "contains" | "notContains" => field.array || is_string || is_spatial,
+            "distanceEqual" | "distanceNotEqual" | "distanceGreaterThan" | "distanceLessThan" | "intersects"
+            | "notIntersects" | "crosses" | "notCrosses" | "overlaps" | "notOverlaps" | "touches"
+            | "notTouches" => is_spatial,
+            _ => return,
+        };
+        if !applies {
+            errors.push(QueryError::TypeMismatch {
+                method: method.to_string(),
+                attribute: attribute.to_string(),
+                attribute_type: attribute_type.to_string(),
+            });
+            return;
+        }
+
+        if (method == "search" || method == "notSearch") && !self.fulltext.contains(attribute) {
+            errors.push(QueryError::MissingFulltextIndex {
+                method: method.to_string(),
+                attribute: attribute.to_string(),
+            });
+        }
+
+        let compares_values = matches!(
+            method,
+            "equal" | "notEqual" | "lessThan" | "lessThanEqual" | "greaterThan" | "greaterThanEqual"
+                | "between" | "notBetween"
+        );
+        if compares_values {
+            for value in query.values.iter().flatten() {
+                if !value_matches(attribute_type, value) {
+                    errors.push(QueryError::InvalidValue {
+                        method: method.to_string(),
+                        attribute: attribute.to_string(),
+                        attribute_type: attribute_type.to_string(),
+                        value: value.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Look up an attribute, resolving `relationship.attribute` paths to the relationship
+    fn field(&self, attribute: &str) -> Option<&Field> {
+        self.fields.get(attribute).or_else(|| {
+            let (relationship, _) = attribute.split_once('.')?;
+            self.fields
+                .get(relationship)
+                .filter(|field| field.attribute_type == "relationship")
+        })
+    }
+}
+
+fn unknown_attribute(method: &str, attribute: &str) -> QueryError {
+    QueryError::UnknownAttribute {
+        method: method.to_string(),
+        attribute: attribute.to_string(),
+    }
+}
+
+/// Whether a query value can be compared with an attribute of the given type
+fn value_matches(attribute_type: &str, value: &Value) -> bool {
+    match attribute_type {
+        _ if value.is_null() => true,
+        "string" | "datetime" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "double" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn validator() -> QueryValidator {
+        let attributes = serde_json::from_value(json!([
+            {"key": "title", "type": "string"},
+            {"key": "status", "type": "string", "format": "enum"},
+            {"key": "age", "type": "integer"},
+            {"key": "tags", "type": "string", "array": true},
+            {"key": "published", "type": "boolean"},
+            {"key": "author", "type": "relationship"},
+            {"key": "location", "type": "point"},
+        ]))
+        .unwrap();
+        let indexes = serde_json::from_value(json!([
+            {"key": "title_search", "type": "fulltext", "attributes": ["title"]},
+        ]))
+        .unwrap();
+
+        QueryValidator::new(attributes, indexes)
+    }
+
+    fn errors(queries: &[Query]) -> Vec<QueryError> {
+        match validator().validate(queries) {
+            Ok(()) => Vec::new(),
+            Err(AppwriteError::InvalidQuery(errors)) => errors,
+            Err(error) => panic!("unexpected error: {}", error),
+        }
+    }
+
+    #[test]
+    fn test_valid_queries() {
+        assert!(errors(&[
+            Query::equal("status", "active") | Query::greater_than("age", 18),
+            Query::search("title", "rust"),
+            Query::contains("tags", "news"),
+            Query::equal("author.name", "Jane"),
+            Query::distance_less_than("location", crate::Point::new(0.0, 0.0), 10.0, true),
+            Query::created_after("2024-01-01T00:00:00Z"),
+            Query::select(vec!["title".to_string(), "author.*".to_string()]),
+            Query::order_desc("$createdAt"),
+            Query::cursor_after("doc-1"),
+            Query::limit(10),
+        ])
+        .is_empty());
+    }
+
+    #[test]
+    fn test_invalid_queries() {
+        let errors = errors(&[
+            Query::and_queries(vec![Query::equal("stauts", "active")]),
+            Query::starts_with("age", "1"),
+            Query::equal("age", "eighteen"),
+            Query::search("status", "act"),
+            Query::intersects("title", crate::Point::new(0.0, 0.0)),
+            Query::cursor_after("a"),
+            Query::cursor_before("b"),
+        ]);
+
+        assert_eq!(
+            errors,
+            vec![
+                unknown_attribute("equal", "stauts"),
+                QueryError::TypeMismatch {
+                    method: "startsWith".to_string(),
+                    attribute: "age".to_string(),
+                    attribute_type: "integer".to_string(),
+                },
+                QueryError::InvalidValue {
+                    method: "equal".to_string(),
+                    attribute: "age".to_string(),
+                    attribute_type: "integer".to_string(),
+                    value: json!("eighteen"),
+                },
+                QueryError::MissingFulltextIndex {
+                    method: "search".to_string(),
+                    attribute: "status".to_string(),
+                },
+                QueryError::TypeMismatch {
+                    method: "intersects".to_string(),
+                    attribute: "title".to_string(),
+                    attribute_type: "string".to_string(),
+                },
+                QueryError::ConflictingCursors,
+            ]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Rate limit information and client-side throttling
+
+use reqwest::header::HeaderMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Rate limit state reported by the server in `x-ratelimit-*` headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Number of requests allowed in the current window
+    pub limit: u64,
+    /// Number of requests left in the current window
+    pub remaining: u64,
+    /// Unix timestamp (in seconds) at which the window resets
+    pub reset: u64,
+}
+
+impl RateLimit {
+    /// Read the rate limit headers of a response, if present
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+
+        Some(Self {
+            limit: header("x-ratelimit-limit")?,
+            remaining: header("x-ratelimit-remaining")?,
+            reset: header("x-ratelimit-reset")?,
+        })
+    }
+}
+
+/// Token bucket limiting how fast a client sends requests
+///
+/// The bucket holds up to `capacity` tokens and refills at `capacity` tokens
+/// per `period`. Every request takes a token, waiting for one to become
+/// available when the bucket is empty. Clones share the same bucket, so a
+/// limiter can throttle several clients together.
+///
+/// # Example
+/// ```rust
+/// use appwrite::{Client, RateLimiter};
+/// use std::time::Duration;
+///
+/// // At most 60 requests per minute
+/// let client = Client::new().set_rate_limiter(RateLimiter::new(60, Duration::from_secs(60)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    refill_per_second: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Allow `capacity` requests per `period`, with bursts of up to `capacity`
+    pub fn new(capacity: u32, period: Duration) -> Self {
+        let capacity = f64::from(capacity.max(1));
+        let period = period.as_secs_f64().max(f64::EPSILON);
+
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: capacity,
+                capacity,
+                refill_per_second: capacity / period,
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// Wait until a request may be sent and take a token for it
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+                bucket.refill();
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.refill_per_second)
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl Bucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.refilled_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("60"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        assert_eq!(RateLimit::from_headers(&headers), None);
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000000"));
+        assert_eq!(
+            RateLimit::from_headers(&headers),
+            Some(RateLimit { limit: 60, remaining: 0, reset: 1700000000 })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_limiter_waits_for_tokens() {
+        let limiter = RateLimiter::new(2, Duration::from_millis(100));
+        let started = Instant::now();
+
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(started.elapsed() < Duration::from_millis(40));
+
+        limiter.acquire().await;
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+}
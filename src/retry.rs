@@ -0,0 +1,299 @@
+//! Retry policy for transient request failures
+
+use reqwest::header::HeaderMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Policy deciding when and how failed requests are retried
+///
+/// Requests are retried when the server answers with one of the retryable
+/// status codes, or when the connection fails before a response arrives.
+/// Only requests using one of the retryable HTTP methods are retried, so
+/// non-idempotent calls such as `POST` are sent once unless configured
+/// otherwise.
+///
+/// # Example
+/// ```rust
+/// use appwrite::{Client, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let client = Client::new().set_retry_policy(
+///     RetryPolicy::new()
+///         .set_max_attempts(5)
+///         .set_initial_backoff(Duration::from_millis(200)),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+    status_codes: Vec<u16>,
+    methods: Vec<String>,
+    respect_retry_after: bool,
+    max_retry_after: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Create a retry policy with the default settings
+    ///
+    /// Up to 3 attempts, starting at a 500ms backoff that doubles up to 30s,
+    /// with jitter. Retries 429, 500, 502, 503 and 504 responses for `GET`,
+    /// `HEAD`, `OPTIONS`, `PUT` and `DELETE` requests, waiting up to 60s when
+    /// the server asks for a delay.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+            status_codes: vec![429, 500, 502, 503, 504],
+            methods: ["GET", "HEAD", "OPTIONS", "PUT", "DELETE"]
+                .iter()
+                .map(|m| m.to_string())
+                .collect(),
+            respect_retry_after: true,
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self::new().set_max_attempts(1)
+    }
+
+    /// Set the maximum number of attempts, including the first request
+    pub fn set_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay before the first retry
+    pub fn set_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the upper bound for the delay between attempts
+    pub fn set_max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the factor the delay grows by after each attempt
+    pub fn set_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Set whether delays are randomized to spread out retries
+    pub fn set_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set the HTTP status codes that trigger a retry
+    pub fn set_status_codes(mut self, status_codes: Vec<u16>) -> Self {
+        self.status_codes = status_codes;
+        self
+    }
+
+    /// Set the HTTP methods whose requests may be retried
+    pub fn set_methods(mut self, methods: Vec<String>) -> Self {
+        self.methods = methods.into_iter().map(|m| m.to_uppercase()).collect();
+        self
+    }
+
+    /// Set whether `Retry-After` and `x-ratelimit-reset` headers decide the delay
+    ///
+    /// `x-ratelimit-reset` is only used for `429` responses, or when
+    /// `x-ratelimit-remaining` is `0`.
+    pub fn set_respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Set the longest delay requested by the server that is waited out
+    ///
+    /// When `Retry-After` or `x-ratelimit-reset` asks for a longer delay, the
+    /// failed response is returned instead of retrying early.
+    pub fn set_max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    /// Get the maximum number of attempts, including the first request
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Whether requests with this HTTP method may be retried
+    pub fn retries_method(&self, method: &str) -> bool {
+        self.methods.iter().any(|m| m.eq_ignore_ascii_case(method))
+    }
+
+    /// Whether a response with this status code should be retried
+    pub fn retries_status(&self, status: u16) -> bool {
+        self.status_codes.contains(&status)
+    }
+
+    /// Delay before the attempt following `attempt` (starting at 1)
+    ///
+    /// `response` is the status code and headers of the failed response, if
+    /// any. When the server says how long to wait, that delay is used as given
+    /// instead of the computed backoff. Returns `None` when it is longer than
+    /// the maximum set with [`RetryPolicy::set_max_retry_after`], in which
+    /// case the request should not be retried.
+    pub fn delay(&self, attempt: u32, response: Option<(u16, &HeaderMap)>) -> Option<Duration> {
+        if self.respect_retry_after {
+            if let Some(delay) = response.and_then(|(status, headers)| server_delay(status, headers)) {
+                return (delay <= self.max_retry_after).then_some(delay);
+            }
+        }
+
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let backoff = backoff.min(self.max_backoff.as_secs_f64());
+
+        if self.jitter {
+            Some(Duration::from_secs_f64(backoff * random_fraction()))
+        } else {
+            Some(Duration::from_secs_f64(backoff))
+        }
+    }
+}
+
+/// Delay requested by the server through `Retry-After` or `x-ratelimit-reset`
+fn server_delay(status: u16, headers: &HeaderMap) -> Option<Duration> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+
+    if let Some(seconds) = header("retry-after") {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    // x-ratelimit-reset is the unix timestamp at which the limit resets. It is
+    // sent on every response of a rate limited endpoint, so it only says how
+    // long to wait when the limit is what failed the request.
+    if status != 429 && header("x-ratelimit-remaining") != Some(0) {
+        return None;
+    }
+
+    header("x-ratelimit-reset").map(|reset| {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Duration::from_secs(reset.saturating_sub(now))
+    })
+}
+
+/// Random number in `[0, 1)`
+///
+/// Built from 53 random bits of a v4 UUID, skipping its fixed version bits
+/// (76 to 79).
+fn random_fraction() -> f64 {
+    let bits = uuid::Uuid::new_v4().as_u128();
+    let random = ((bits >> 80) << 5) | ((bits >> 71) & 0x1f);
+    random as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_exponential_backoff() {
+        let policy = RetryPolicy::new()
+            .set_jitter(false)
+            .set_initial_backoff(Duration::from_millis(100))
+            .set_max_backoff(Duration::from_millis(1000));
+
+        assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(2, None), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(3, None), Some(Duration::from_millis(400)));
+        assert_eq!(policy.delay(5, None), Some(Duration::from_millis(1000)));
+    }
+
+    #[test]
+    fn test_jitter_stays_below_backoff() {
+        let policy = RetryPolicy::new().set_initial_backoff(Duration::from_millis(100));
+
+        for _ in 0..100 {
+            assert!(policy.delay(1, None).unwrap() < Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_random_fraction_spans_unit_interval() {
+        let samples: Vec<f64> = (0..1000).map(|_| random_fraction()).collect();
+
+        assert!(samples.iter().all(|f| (0.0..1.0).contains(f)));
+        assert!(samples.iter().any(|f| *f < 0.25));
+        assert!(samples.iter().any(|f| *f > 0.75));
+    }
+
+    #[test]
+    fn test_server_delay() {
+        let policy = RetryPolicy::new().set_max_backoff(Duration::from_secs(10));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("3"));
+        assert_eq!(policy.delay(1, Some((503, &headers))), Some(Duration::from_secs(3)));
+
+        // The server delay is not shortened to the maximum backoff
+        headers.insert("retry-after", HeaderValue::from_static("45"));
+        assert_eq!(policy.delay(1, Some((503, &headers))), Some(Duration::from_secs(45)));
+
+        headers.insert("retry-after", HeaderValue::from_static("120"));
+        assert_eq!(policy.delay(1, Some((503, &headers))), None);
+
+        let ignoring = policy.clone().set_respect_retry_after(false).set_jitter(false);
+        assert_eq!(ignoring.delay(1, Some((503, &headers))), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_rate_limit_reset_only_delays_rate_limited_requests() {
+        let policy = RetryPolicy::new().set_jitter(false);
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from(5));
+        headers.insert("x-ratelimit-reset", HeaderValue::from(now + 20));
+        assert_eq!(policy.delay(1, Some((503, &headers))), Some(Duration::from_millis(500)));
+
+        let delay = policy.delay(1, Some((429, &headers))).unwrap();
+        assert!(delay > Duration::from_secs(15) && delay <= Duration::from_secs(20));
+
+        headers.insert("x-ratelimit-remaining", HeaderValue::from(0));
+        assert!(policy.delay(1, Some((503, &headers))).unwrap() > Duration::from_secs(15));
+    }
+
+    #[test]
+    fn test_retryable_requests() {
+        let policy = RetryPolicy::new();
+        assert!(policy.retries_method("get"));
+        assert!(!policy.retries_method("POST"));
+        assert!(policy.retries_status(503));
+        assert!(!policy.retries_status(404));
+
+        let policy = policy.set_methods(vec!["post".to_string()]);
+        assert!(policy.retries_method("POST"));
+        assert!(!policy.retries_method("GET"));
+
+        assert_eq!(RetryPolicy::none().max_attempts(), 1);
+    }
+}
@@ -1,6 +1,12 @@
 //! Account service for user authentication and management
 
-use crate::{client::Client, error::Result, enums::*};
+use crate::{
+    client::Client,
+    enums::*,
+    error::Result,
+    id::{validate_id, validate_new_id},
+    models::{Preferences, Session, SessionList, Token, User},
+};
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 
@@ -34,23 +40,23 @@ impl<'a> Account<'a> {
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get(&self) -> Result<Value> {
+    pub async fn get(&self) -> Result<User> {
         self.client
-            .call("get", "/account", None, None, None)
+            .call_as("get", "/account", None, None)
             .await
     }
 
     /// Create a new user account
     ///
     /// # Arguments
-    /// * `user_id` - User ID. Choose a custom ID or generate a random ID
+    /// * `user_id` - User ID. Choose a custom ID or generate one with [`ID::unique`](crate::ID::unique)
     /// * `email` - User email
     /// * `password` - User password. Must be between 8 and 256 chars
     /// * `name` - User name. Optional
     ///
     /// # Example
     /// ```rust,no_run
-    /// use appwrite::{Client, Account};
+    /// use appwrite::{Client, Account, ID};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -60,7 +66,7 @@ impl<'a> Account<'a> {
     ///
     ///     let account = Account::new(&client);
     ///     let user = account.create(
-    ///         "unique_user_id",
+    ///         &ID::unique(),
     ///         "user@example.com",
     ///         "securepassword",
     ///         Some("John Doe")
@@ -75,10 +81,8 @@ impl<'a> Account<'a> {
         email: &str,
         password: &str,
         name: Option<&str>,
-    ) -> Result<Value> {
-        if user_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("user_id"));
-        }
+    ) -> Result<User> {
+        validate_new_id(user_id, "user_id")?;
         if email.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("email"));
         }
@@ -99,7 +103,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("post", "/account", Some(headers), Some(params), None)
+            .call_as("post", "/account", Some(headers), Some(params))
             .await
     }
 
@@ -108,7 +112,7 @@ impl<'a> Account<'a> {
     /// # Arguments
     /// * `email` - User email
     /// * `password` - User password for security verification
-    pub async fn update_email(&self, email: &str, password: &str) -> Result<Value> {
+    pub async fn update_email(&self, email: &str, password: &str) -> Result<User> {
         if email.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("email"));
         }
@@ -124,7 +128,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("patch", "/account/email", Some(headers), Some(params), None)
+            .call_as("patch", "/account/email", Some(headers), Some(params))
             .await
     }
 
@@ -132,7 +136,7 @@ impl<'a> Account<'a> {
     ///
     /// # Arguments
     /// * `name` - User name
-    pub async fn update_name(&self, name: &str) -> Result<Value> {
+    pub async fn update_name(&self, name: &str) -> Result<User> {
         if name.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("name"));
         }
@@ -144,7 +148,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("patch", "/account/name", Some(headers), Some(params), None)
+            .call_as("patch", "/account/name", Some(headers), Some(params))
             .await
     }
 
@@ -153,7 +157,7 @@ impl<'a> Account<'a> {
     /// # Arguments
     /// * `password` - New user password
     /// * `old_password` - Current user password (optional if user has no password set)
-    pub async fn update_password(&self, password: &str, old_password: Option<&str>) -> Result<Value> {
+    pub async fn update_password(&self, password: &str, old_password: Option<&str>) -> Result<User> {
         if password.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("password"));
         }
@@ -169,7 +173,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("patch", "/account/password", Some(headers), Some(params), None)
+            .call_as("patch", "/account/password", Some(headers), Some(params))
             .await
     }
 
@@ -178,7 +182,7 @@ impl<'a> Account<'a> {
     /// # Arguments
     /// * `email` - User email
     /// * `password` - User password
-    pub async fn create_email_password_session(&self, email: &str, password: &str) -> Result<Value> {
+    pub async fn create_email_password_session(&self, email: &str, password: &str) -> Result<Session> {
         if email.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("email"));
         }
@@ -194,17 +198,17 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("post", "/account/sessions/email", Some(headers), Some(params), None)
+            .call_as("post", "/account/sessions/email", Some(headers), Some(params))
             .await
     }
 
     /// Create anonymous session
-    pub async fn create_anonymous_session(&self) -> Result<Value> {
+    pub async fn create_anonymous_session(&self) -> Result<Session> {
         let headers = HashMap::new();
         let params = Map::new();
 
         self.client
-            .call("post", "/account/sessions/anonymous", Some(headers), Some(params), None)
+            .call_as("post", "/account/sessions/anonymous", Some(headers), Some(params))
             .await
     }
 
@@ -215,15 +219,17 @@ impl<'a> Account<'a> {
     /// * `success` - URL to redirect back to your app after a successful login attempt
     /// * `failure` - URL to redirect back to your app after a failed login attempt
     /// * `scopes` - A list of custom OAuth2 scopes (optional)
+    ///
+    /// Returns the URL to send the user's browser to. Appwrite redirects it
+    /// to the provider's sign in page, and then back to `success` or `failure`.
     pub async fn create_oauth2_session(
         &self,
         provider: OAuthProvider,
         success: Option<&str>,
         failure: Option<&str>,
         scopes: Option<Vec<String>>,
-    ) -> Result<Value> {
+    ) -> Result<String> {
         let mut params = Map::new();
-        params.insert("provider".to_string(), Value::String(provider.as_ref().to_string()));
         
         if let Some(success) = success {
             params.insert("success".to_string(), Value::String(success.to_string()));
@@ -236,58 +242,55 @@ impl<'a> Account<'a> {
             params.insert("scopes".to_string(), Value::Array(scopes_value));
         }
 
-        let headers = HashMap::new();
-
         self.client
-            .call("get", &format!("/account/sessions/oauth2/{}", provider.as_ref()), Some(headers), Some(params), Some("location"))
-            .await
+            .browser_url(&format!("/account/sessions/oauth2/{}", provider.as_ref()), &params)
     }
 
     /// Get current session
-    pub async fn get_session(&self, session_id: &str) -> Result<Value> {
-        if session_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("session_id"));
-        }
+    pub async fn get_session(&self, session_id: &str) -> Result<Session> {
+        validate_id(session_id, "session_id")?;
 
         let path = format!("/account/sessions/{}", session_id);
         
         self.client
-            .call("get", &path, None, None, None)
+            .call_as("get", &path, None, None)
             .await
     }
 
     /// List all user sessions
-    pub async fn list_sessions(&self) -> Result<Value> {
+    pub async fn list_sessions(&self) -> Result<SessionList> {
         self.client
-            .call("get", "/account/sessions", None, None, None)
+            .call_as("get", "/account/sessions", None, None)
             .await
     }
 
     /// Delete a specific session
-    pub async fn delete_session(&self, session_id: &str) -> Result<Value> {
-        if session_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("session_id"));
-        }
+    pub async fn delete_session(&self, session_id: &str) -> Result<()> {
+        validate_id(session_id, "session_id")?;
 
         let path = format!("/account/sessions/{}", session_id);
         
         self.client
-            .call("delete", &path, None, None, None)
-            .await
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
     }
 
     /// Delete all user sessions
-    pub async fn delete_sessions(&self) -> Result<Value> {
+    pub async fn delete_sessions(&self) -> Result<()> {
         self.client
-            .call("delete", "/account/sessions", None, None, None)
-            .await
+            .call("delete", "/account/sessions", None, None)
+            .await?;
+
+        Ok(())
     }
 
     /// Create email verification
     ///
     /// # Arguments
     /// * `url` - URL to redirect the user back to your app from the verification email
-    pub async fn create_verification(&self, url: &str) -> Result<Value> {
+    pub async fn create_verification(&self, url: &str) -> Result<Token> {
         if url.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("url"));
         }
@@ -299,7 +302,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("post", "/account/verification", Some(headers), Some(params), None)
+            .call_as("post", "/account/verification", Some(headers), Some(params))
             .await
     }
 
@@ -308,10 +311,8 @@ impl<'a> Account<'a> {
     /// # Arguments
     /// * `user_id` - User ID
     /// * `secret` - Valid verification token
-    pub async fn update_verification(&self, user_id: &str, secret: &str) -> Result<Value> {
-        if user_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("user_id"));
-        }
+    pub async fn update_verification(&self, user_id: &str, secret: &str) -> Result<Token> {
+        validate_id(user_id, "user_id")?;
         if secret.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("secret"));
         }
@@ -324,7 +325,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("put", "/account/verification", Some(headers), Some(params), None)
+            .call_as("put", "/account/verification", Some(headers), Some(params))
             .await
     }
 
@@ -333,7 +334,7 @@ impl<'a> Account<'a> {
     /// # Arguments
     /// * `email` - User email
     /// * `url` - URL to redirect the user back to your app from the recovery email
-    pub async fn create_recovery(&self, email: &str, url: &str) -> Result<Value> {
+    pub async fn create_recovery(&self, email: &str, url: &str) -> Result<Token> {
         if email.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("email"));
         }
@@ -349,7 +350,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("post", "/account/recovery", Some(headers), Some(params), None)
+            .call_as("post", "/account/recovery", Some(headers), Some(params))
             .await
     }
 
@@ -366,10 +367,8 @@ impl<'a> Account<'a> {
         secret: &str,
         password: &str,
         password_again: &str,
-    ) -> Result<Value> {
-        if user_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("user_id"));
-        }
+    ) -> Result<Token> {
+        validate_id(user_id, "user_id")?;
         if secret.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("secret"));
         }
@@ -390,14 +389,14 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("put", "/account/recovery", Some(headers), Some(params), None)
+            .call_as("put", "/account/recovery", Some(headers), Some(params))
             .await
     }
 
     /// Get user preferences
-    pub async fn get_prefs(&self) -> Result<Value> {
+    pub async fn get_prefs(&self) -> Result<Preferences> {
         self.client
-            .call("get", "/account/prefs", None, None, None)
+            .call_as("get", "/account/prefs", None, None)
             .await
     }
 
@@ -405,7 +404,7 @@ impl<'a> Account<'a> {
     ///
     /// # Arguments
     /// * `prefs` - Preferences key-value JSON object
-    pub async fn update_prefs(&self, prefs: Value) -> Result<Value> {
+    pub async fn update_prefs(&self, prefs: Value) -> Result<User> {
         let mut params = Map::new();
         params.insert("prefs".to_string(), prefs);
 
@@ -413,7 +412,7 @@ impl<'a> Account<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("patch", "/account/prefs", Some(headers), Some(params), None)
+            .call_as("patch", "/account/prefs", Some(headers), Some(params))
             .await
     }
 }
\ No newline at end of file
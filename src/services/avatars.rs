@@ -6,6 +6,7 @@ use serde_json::Value;
 /// Avatars service for generating avatar images
 #[derive(Debug, Clone)]
 pub struct Avatars<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
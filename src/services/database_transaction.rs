@@ -0,0 +1,159 @@
+//! Staged document operations committed atomically
+
+use crate::{
+    error::Result,
+    id::{validate_id, validate_new_id},
+    models::Transaction,
+    permission::{permissions_value, Permission},
+    services::databases::{document_data, Databases},
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// A transaction on the Databases service
+///
+/// Operations staged on the transaction are stored by the server and applied
+/// together when the transaction is committed. Nothing is visible to other
+/// requests before then, and a rollback discards everything staged. A
+/// transaction left open is rolled back once its TTL expires.
+///
+/// Created with [`Databases::transaction`], or used through
+/// [`Databases::with_transaction`] to commit or roll back automatically.
+///
+/// # Example
+/// ```rust,no_run
+/// use appwrite::{Client, Databases};
+/// use serde_json::json;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new()
+///         .set_endpoint("https://cloud.appwrite.io/v1")?
+///         .set_project("your-project-id")
+///         .set_key("your-api-key");
+///
+///     let databases = Databases::new(&client);
+///     let transaction = databases.transaction(None).await?;
+///     transaction
+///         .create_document("shop", "orders", "order-1", &json!({"total": 30}), None)
+///         .await?;
+///     transaction
+///         .update_document("shop", "stock", "item-1", &json!({"quantity": 4}), None)
+///         .await?;
+///     transaction.commit().await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DatabaseTransaction<'a> {
+    databases: Databases<'a>,
+    id: String,
+}
+
+impl<'a> DatabaseTransaction<'a> {
+    pub(crate) fn new(databases: Databases<'a>, id: String) -> Self {
+        Self { databases, id }
+    }
+
+    /// Transaction ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Stage the creation of a document
+    pub async fn create_document<T: Serialize>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        data: &T,
+        permissions: Option<Vec<Permission>>,
+    ) -> Result<Transaction> {
+        let data = with_permissions(document_data(data)?, permissions)?;
+        self.stage("create", database_id, collection_id, document_id, Some(data))
+            .await
+    }
+
+    /// Stage an update of a document
+    ///
+    /// Only the attributes present in `data` are changed.
+    pub async fn update_document<T: Serialize>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        data: &T,
+        permissions: Option<Vec<Permission>>,
+    ) -> Result<Transaction> {
+        let data = with_permissions(document_data(data)?, permissions)?;
+        self.stage("update", database_id, collection_id, document_id, Some(data))
+            .await
+    }
+
+    /// Stage the deletion of a document
+    pub async fn delete_document(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+    ) -> Result<Transaction> {
+        self.stage("delete", database_id, collection_id, document_id, None)
+            .await
+    }
+
+    /// Apply every staged operation
+    ///
+    /// Fails without applying anything if any operation conflicts with the
+    /// current state of the database.
+    pub async fn commit(&self) -> Result<Transaction> {
+        self.databases
+            .update_transaction(&self.id, true, false)
+            .await
+    }
+
+    /// Discard every staged operation
+    pub async fn rollback(&self) -> Result<Transaction> {
+        self.databases
+            .update_transaction(&self.id, false, true)
+            .await
+    }
+
+    async fn stage(
+        &self,
+        action: &str,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        data: Option<Value>,
+    ) -> Result<Transaction> {
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
+        if action == "create" {
+            validate_new_id(document_id, "document_id")?;
+        } else {
+            validate_id(document_id, "document_id")?;
+        }
+
+        let mut operation = Map::new();
+        operation.insert("action".to_string(), Value::String(action.to_string()));
+        operation.insert("databaseId".to_string(), Value::String(database_id.to_string()));
+        operation.insert("collectionId".to_string(), Value::String(collection_id.to_string()));
+        operation.insert("documentId".to_string(), Value::String(document_id.to_string()));
+        if let Some(data) = data {
+            operation.insert("data".to_string(), data);
+        }
+
+        self.databases
+            .create_operations(&self.id, vec![Value::Object(operation)])
+            .await
+    }
+}
+
+/// Add permissions to staged document data, where they are system attributes
+fn with_permissions(mut data: Value, permissions: Option<Vec<Permission>>) -> Result<Value> {
+    if let (Some(permissions), Some(object)) = (permissions, data.as_object_mut()) {
+        object.insert("$permissions".to_string(), permissions_value(permissions)?);
+    }
+
+    Ok(data)
+}
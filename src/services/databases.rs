@@ -1,8 +1,31 @@
 //! Databases service for database operations
 
-use crate::{client::Client, error::Result, enums::*};
-use serde_json::{Map, Value};
+use crate::{
+    client::Client,
+    enums::*,
+    error::Result,
+    id::{validate_id, validate_new_id},
+    geometry::{Line, Point, Polygon},
+    pagination::paginate,
+    permission::{permissions_value, Permission},
+    query::Query,
+    query_validator::QueryValidator,
+    models::{
+        Attribute, AttributeBoolean, AttributeDatetime, AttributeEmail, AttributeEnum,
+        AttributeFloat, AttributeInteger, AttributeIp, AttributeLine, AttributeList, AttributePoint,
+        AttributePolygon, AttributeRelationship, AttributeString, AttributeUrl, Collection, CollectionList, Database, DatabaseList,
+        Document, DocumentList, Index, IndexList, Transaction, TransactionList,
+    },
+    services::DatabaseTransaction,
+};
+use futures_util::Stream;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Number, Value};
 use std::collections::HashMap;
+use std::future::Future;
+
+/// Maximum number of documents sent in one bulk request
+pub const BULK_BATCH_SIZE: usize = 100;
 
 /// Databases service for database operations
 #[derive(Debug, Clone)]
@@ -17,11 +40,11 @@ impl<'a> Databases<'a> {
     }
 
     /// List all databases
-    pub async fn list(&self, queries: Option<Vec<String>>, search: Option<&str>) -> Result<Value> {
+    pub async fn list(&self, queries: Option<Vec<Query>>, search: Option<&str>) -> Result<DatabaseList> {
         let mut params = Map::new();
         
         if let Some(queries) = queries {
-            let queries_value: Vec<Value> = queries.into_iter().map(Value::String).collect();
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
             params.insert("queries".to_string(), Value::Array(queries_value));
         }
         if let Some(search) = search {
@@ -29,15 +52,31 @@ impl<'a> Databases<'a> {
         }
 
         self.client
-            .call("get", "/databases", None, Some(params), None)
+            .call_as("get", "/databases", None, Some(params))
             .await
     }
 
+    /// Stream all databases, fetching them page by page
+    ///
+    /// See [`Databases::list_documents_stream`] for how `queries` are handled.
+    pub fn list_stream(
+        &self,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> impl Stream<Item = Result<Database>> + 'a {
+        let databases = self.clone();
+        let search = search.map(str::to_string);
+
+        paginate(queries, move |queries| {
+            let databases = databases.clone();
+            let search = search.clone();
+            async move { databases.list(Some(queries), search.as_deref()).await }
+        })
+    }
+
     /// Create a new database
-    pub async fn create(&self, database_id: &str, name: &str, enabled: Option<bool>) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
+    pub async fn create(&self, database_id: &str, name: &str, enabled: Option<bool>) -> Result<Database> {
+        validate_new_id(database_id, "database_id")?;
         if name.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("name"));
         }
@@ -54,20 +93,80 @@ impl<'a> Databases<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("post", "/databases", Some(headers), Some(params), None)
+            .call_as("post", "/databases", Some(headers), Some(params))
             .await
     }
 
     /// Get a database by ID
-    pub async fn get(&self, database_id: &str) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
+    pub async fn get(&self, database_id: &str) -> Result<Database> {
+        validate_id(database_id, "database_id")?;
 
         let path = format!("/databases/{}", database_id);
         
         self.client
-            .call("get", &path, None, None, None)
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Update a database
+    pub async fn update(&self, database_id: &str, name: &str, enabled: Option<bool>) -> Result<Database> {
+        validate_id(database_id, "database_id")?;
+        if name.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("name"));
+        }
+
+        let mut params = Map::new();
+        params.insert("name".to_string(), Value::String(name.to_string()));
+
+        if let Some(enabled) = enabled {
+            params.insert("enabled".to_string(), Value::Bool(enabled));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        let path = format!("/databases/{}", database_id);
+
+        self.client
+            .call_as("put", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Delete a database with all its collections and documents
+    pub async fn delete(&self, database_id: &str) -> Result<()> {
+        validate_id(database_id, "database_id")?;
+
+        let path = format!("/databases/{}", database_id);
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// List the collections of a database
+    pub async fn list_collections(
+        &self,
+        database_id: &str,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> Result<CollectionList> {
+        validate_id(database_id, "database_id")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+        if let Some(search) = search {
+            params.insert("search".to_string(), Value::String(search.to_string()));
+        }
+
+        let path = format!("/databases/{}/collections", database_id);
+
+        self.client
+            .call_as("get", &path, None, Some(params))
             .await
     }
 
@@ -77,16 +176,12 @@ impl<'a> Databases<'a> {
         database_id: &str,
         collection_id: &str,
         name: &str,
-        permissions: Option<Vec<String>>,
+        permissions: Option<Vec<Permission>>,
         document_security: Option<bool>,
         enabled: Option<bool>,
-    ) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
-        if collection_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("collection_id"));
-        }
+    ) -> Result<Collection> {
+        validate_id(database_id, "database_id")?;
+        validate_new_id(collection_id, "collection_id")?;
         if name.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("name"));
         }
@@ -96,8 +191,7 @@ impl<'a> Databases<'a> {
         params.insert("name".to_string(), Value::String(name.to_string()));
         
         if let Some(permissions) = permissions {
-            let perms_value: Vec<Value> = permissions.into_iter().map(Value::String).collect();
-            params.insert("permissions".to_string(), Value::Array(perms_value));
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
         }
         if let Some(document_security) = document_security {
             params.insert("documentSecurity".to_string(), Value::Bool(document_security));
@@ -112,11 +206,132 @@ impl<'a> Databases<'a> {
         let path = format!("/databases/{}/collections", database_id);
         
         self.client
-            .call("post", &path, Some(headers), Some(params), None)
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Get a collection, including its attributes and indexes
+    pub async fn get_collection(&self, database_id: &str, collection_id: &str) -> Result<Collection> {
+        let path = Self::collection_path(database_id, collection_id, "")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Fetch a collection's schema to validate queries before sending them
+    ///
+    /// See [`QueryValidator`].
+    pub async fn query_validator(&self, database_id: &str, collection_id: &str) -> Result<QueryValidator> {
+        let collection = self.get_collection(database_id, collection_id).await?;
+
+        Ok(QueryValidator::from_collection(&collection))
+    }
+
+    /// Update a collection
+    ///
+    /// `permissions` replaces the collection permissions when given, and the
+    /// other settings keep their server defaults when omitted.
+    pub async fn update_collection(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        name: &str,
+        permissions: Option<Vec<Permission>>,
+        document_security: Option<bool>,
+        enabled: Option<bool>,
+    ) -> Result<Collection> {
+        let path = Self::collection_path(database_id, collection_id, "")?;
+
+        if name.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("name"));
+        }
+
+        let mut params = Map::new();
+        params.insert("name".to_string(), Value::String(name.to_string()));
+
+        if let Some(permissions) = permissions {
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
+        }
+        if let Some(document_security) = document_security {
+            params.insert("documentSecurity".to_string(), Value::Bool(document_security));
+        }
+        if let Some(enabled) = enabled {
+            params.insert("enabled".to_string(), Value::Bool(enabled));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("put", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Delete a collection with all its documents
+    pub async fn delete_collection(&self, database_id: &str, collection_id: &str) -> Result<()> {
+        let path = Self::collection_path(database_id, collection_id, "")?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// List the attributes of a collection
+    pub async fn list_attributes(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<AttributeList> {
+        let path = Self::collection_path(database_id, collection_id, "/attributes")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// Get an attribute by key
+    pub async fn get_attribute(&self, database_id: &str, collection_id: &str, key: &str) -> Result<Attribute> {
+        if key.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("key"));
+        }
+
+        let path = Self::collection_path(database_id, collection_id, &format!("/attributes/{}", key))?;
+
+        self.client
+            .call_as("get", &path, None, None)
             .await
     }
 
+    /// Delete an attribute
+    ///
+    /// The attribute is removed in the background, and its status is
+    /// `deleting` until then.
+    pub async fn delete_attribute(&self, database_id: &str, collection_id: &str, key: &str) -> Result<()> {
+        if key.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("key"));
+        }
+
+        let path = Self::collection_path(database_id, collection_id, &format!("/attributes/{}", key))?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
     /// Create a string attribute
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_string_attribute(
         &self,
         database_id: &str,
@@ -127,7 +342,7 @@ impl<'a> Databases<'a> {
         default: Option<&str>,
         array: Option<bool>,
         encrypt: Option<bool>,
-    ) -> Result<Value> {
+    ) -> Result<AttributeString> {
         self.create_attribute(database_id, collection_id, "string", |params| {
             params.insert("key".to_string(), Value::String(key.to_string()));
             params.insert("size".to_string(), Value::Number(size.into()));
@@ -154,7 +369,7 @@ impl<'a> Databases<'a> {
         required: bool,
         default: Option<&str>,
         array: Option<bool>,
-    ) -> Result<Value> {
+    ) -> Result<AttributeEmail> {
         self.create_attribute(database_id, collection_id, "email", |params| {
             params.insert("key".to_string(), Value::String(key.to_string()));
             params.insert("required".to_string(), Value::Bool(required));
@@ -169,6 +384,7 @@ impl<'a> Databases<'a> {
     }
 
     /// Create an integer attribute
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_integer_attribute(
         &self,
         database_id: &str,
@@ -179,7 +395,7 @@ impl<'a> Databases<'a> {
         max: Option<i64>,
         default: Option<i64>,
         array: Option<bool>,
-    ) -> Result<Value> {
+    ) -> Result<AttributeInteger> {
         self.create_attribute(database_id, collection_id, "integer", |params| {
             params.insert("key".to_string(), Value::String(key.to_string()));
             params.insert("required".to_string(), Value::Bool(required));
@@ -199,164 +415,1044 @@ impl<'a> Databases<'a> {
         }).await
     }
 
-    /// Create a document
-    pub async fn create_document(
+    /// Create a float attribute
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_float_attribute(
         &self,
         database_id: &str,
         collection_id: &str,
-        document_id: &str,
-        data: Value,
-        permissions: Option<Vec<String>>,
-    ) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
-        if collection_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("collection_id"));
-        }
-        if document_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("document_id"));
-        }
-
-        let mut params = Map::new();
-        params.insert("documentId".to_string(), Value::String(document_id.to_string()));
-        params.insert("data".to_string(), data);
-        
-        if let Some(permissions) = permissions {
-            let perms_value: Vec<Value> = permissions.into_iter().map(Value::String).collect();
-            params.insert("permissions".to_string(), Value::Array(perms_value));
-        }
-
-        let mut headers = HashMap::new();
-        headers.insert("content-type".to_string(), "application/json".to_string());
+        key: &str,
+        required: bool,
+        min: Option<f64>,
+        max: Option<f64>,
+        default: Option<f64>,
+        array: Option<bool>,
+    ) -> Result<AttributeFloat> {
+        self.create_attribute(database_id, collection_id, "float", |params| {
+            params.insert("key".to_string(), Value::String(key.to_string()));
+            params.insert("required".to_string(), Value::Bool(required));
 
-        let path = format!("/databases/{}/collections/{}/documents", database_id, collection_id);
-        
-        self.client
-            .call("post", &path, Some(headers), Some(params), None)
-            .await
+            if let Some(min) = min {
+                params.insert("min".to_string(), Value::from(min));
+            }
+            if let Some(max) = max {
+                params.insert("max".to_string(), Value::from(max));
+            }
+            if let Some(default) = default {
+                params.insert("default".to_string(), Value::from(default));
+            }
+            if let Some(array) = array {
+                params.insert("array".to_string(), Value::Bool(array));
+            }
+        }).await
     }
 
-    /// Get a document
-    pub async fn get_document(
+    /// Create a boolean attribute
+    pub async fn create_boolean_attribute(
         &self,
         database_id: &str,
         collection_id: &str,
-        document_id: &str,
-        queries: Option<Vec<String>>,
-    ) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
-        if collection_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("collection_id"));
-        }
-        if document_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("document_id"));
-        }
-
-        let mut params = Map::new();
-        if let Some(queries) = queries {
-            let queries_value: Vec<Value> = queries.into_iter().map(Value::String).collect();
-            params.insert("queries".to_string(), Value::Array(queries_value));
-        }
+        key: &str,
+        required: bool,
+        default: Option<bool>,
+        array: Option<bool>,
+    ) -> Result<AttributeBoolean> {
+        self.create_attribute(database_id, collection_id, "boolean", |params| {
+            params.insert("key".to_string(), Value::String(key.to_string()));
+            params.insert("required".to_string(), Value::Bool(required));
 
-        let path = format!("/databases/{}/collections/{}/documents/{}", database_id, collection_id, document_id);
-        
-        self.client
-            .call("get", &path, None, Some(params), None)
-            .await
+            if let Some(default) = default {
+                params.insert("default".to_string(), Value::Bool(default));
+            }
+            if let Some(array) = array {
+                params.insert("array".to_string(), Value::Bool(array));
+            }
+        }).await
     }
 
-    /// Update a document
-    pub async fn update_document(
+    /// Create a datetime attribute
+    ///
+    /// `default` is a date in ISO 8601 format.
+    pub async fn create_datetime_attribute(
         &self,
         database_id: &str,
         collection_id: &str,
-        document_id: &str,
-        data: Option<Value>,
-        permissions: Option<Vec<String>>,
-    ) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
-        if collection_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("collection_id"));
-        }
-        if document_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("document_id"));
-        }
-
-        let mut params = Map::new();
-        
-        if let Some(data) = data {
-            params.insert("data".to_string(), data);
-        }
-        if let Some(permissions) = permissions {
-            let perms_value: Vec<Value> = permissions.into_iter().map(Value::String).collect();
-            params.insert("permissions".to_string(), Value::Array(perms_value));
-        }
-
-        let mut headers = HashMap::new();
-        headers.insert("content-type".to_string(), "application/json".to_string());
+        key: &str,
+        required: bool,
+        default: Option<&str>,
+        array: Option<bool>,
+    ) -> Result<AttributeDatetime> {
+        self.create_attribute(database_id, collection_id, "datetime", |params| {
+            params.insert("key".to_string(), Value::String(key.to_string()));
+            params.insert("required".to_string(), Value::Bool(required));
 
-        let path = format!("/databases/{}/collections/{}/documents/{}", database_id, collection_id, document_id);
-        
-        self.client
-            .call("patch", &path, Some(headers), Some(params), None)
-            .await
+            if let Some(default) = default {
+                params.insert("default".to_string(), Value::String(default.to_string()));
+            }
+            if let Some(array) = array {
+                params.insert("array".to_string(), Value::Bool(array));
+            }
+        }).await
     }
 
-    /// List documents
-    pub async fn list_documents(
+    /// Create an enum attribute
+    ///
+    /// `elements` lists the allowed values.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_enum_attribute(
         &self,
         database_id: &str,
         collection_id: &str,
-        queries: Option<Vec<String>>,
-    ) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
-        if collection_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("collection_id"));
-        }
-
-        let mut params = Map::new();
-        if let Some(queries) = queries {
-            let queries_value: Vec<Value> = queries.into_iter().map(Value::String).collect();
-            params.insert("queries".to_string(), Value::Array(queries_value));
-        }
+        key: &str,
+        elements: Vec<String>,
+        required: bool,
+        default: Option<&str>,
+        array: Option<bool>,
+    ) -> Result<AttributeEnum> {
+        self.create_attribute(database_id, collection_id, "enum", |params| {
+            params.insert("key".to_string(), Value::String(key.to_string()));
+            let elements_value: Vec<Value> = elements.into_iter().map(Value::String).collect();
+            params.insert("elements".to_string(), Value::Array(elements_value));
+            params.insert("required".to_string(), Value::Bool(required));
 
-        let path = format!("/databases/{}/collections/{}/documents", database_id, collection_id);
-        
-        self.client
-            .call("get", &path, None, Some(params), None)
-            .await
+            if let Some(default) = default {
+                params.insert("default".to_string(), Value::String(default.to_string()));
+            }
+            if let Some(array) = array {
+                params.insert("array".to_string(), Value::Bool(array));
+            }
+        }).await
     }
 
-    /// Create an index
-    pub async fn create_index(
+    /// Create an IP address attribute
+    pub async fn create_ip_attribute(
         &self,
         database_id: &str,
         collection_id: &str,
         key: &str,
-        index_type: IndexType,
-        attributes: Vec<String>,
-        orders: Option<Vec<String>>,
-    ) -> Result<Value> {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
-        if collection_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("collection_id"));
-        }
-        if key.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("key"));
-        }
-
-        let mut params = Map::new();
-        params.insert("key".to_string(), Value::String(key.to_string()));
-        params.insert("type".to_string(), Value::String(index_type.as_ref().to_string()));
+        required: bool,
+        default: Option<&str>,
+        array: Option<bool>,
+    ) -> Result<AttributeIp> {
+        self.create_attribute(database_id, collection_id, "ip", |params| {
+            params.insert("key".to_string(), Value::String(key.to_string()));
+            params.insert("required".to_string(), Value::Bool(required));
+
+            if let Some(default) = default {
+                params.insert("default".to_string(), Value::String(default.to_string()));
+            }
+            if let Some(array) = array {
+                params.insert("array".to_string(), Value::Bool(array));
+            }
+        }).await
+    }
+
+    /// Create a URL attribute
+    pub async fn create_url_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<&str>,
+        array: Option<bool>,
+    ) -> Result<AttributeUrl> {
+        self.create_attribute(database_id, collection_id, "url", |params| {
+            params.insert("key".to_string(), Value::String(key.to_string()));
+            params.insert("required".to_string(), Value::Bool(required));
+
+            if let Some(default) = default {
+                params.insert("default".to_string(), Value::String(default.to_string()));
+            }
+            if let Some(array) = array {
+                params.insert("array".to_string(), Value::Bool(array));
+            }
+        }).await
+    }
+
+    /// Create a point attribute
+    ///
+    /// Spatial attributes cannot hold arrays. Index them with
+    /// [`IndexType::Spatial`] to query them efficiently.
+    pub async fn create_point_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<Point>,
+    ) -> Result<AttributePoint> {
+        self.create_spatial_attribute(database_id, collection_id, "point", key, required, default.map(Value::from))
+            .await
+    }
+
+    /// Create a line attribute
+    pub async fn create_line_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<Line>,
+    ) -> Result<AttributeLine> {
+        self.create_spatial_attribute(database_id, collection_id, "line", key, required, default.map(Value::from))
+            .await
+    }
+
+    /// Create a polygon attribute
+    pub async fn create_polygon_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<Polygon>,
+    ) -> Result<AttributePolygon> {
+        self.create_spatial_attribute(database_id, collection_id, "polygon", key, required, default.map(Value::from))
+            .await
+    }
+
+    /// Create a relationship attribute
+    ///
+    /// # Arguments
+    /// * `related_collection_id` - Collection the attribute points to
+    /// * `relation_type` - Relationship type
+    /// * `two_way` - Whether to add an attribute to the related collection too. Optional
+    /// * `key` - Attribute key. Defaults to the related collection ID
+    /// * `two_way_key` - Key of the attribute in the related collection. Optional
+    /// * `on_delete` - What happens to related documents on delete. Defaults to restrict
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Databases, RelationMutate, RelationshipType};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let databases = Databases::new(&client);
+    ///     let attribute = databases.create_relationship_attribute(
+    ///         "database_id",
+    ///         "authors",
+    ///         "books",
+    ///         RelationshipType::OneToMany,
+    ///         Some(true),
+    ///         Some("books"),
+    ///         Some("author"),
+    ///         Some(RelationMutate::Cascade),
+    ///     ).await?;
+    ///     println!("Relationship created: {:?}", attribute);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_relationship_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        related_collection_id: &str,
+        relation_type: RelationshipType,
+        two_way: Option<bool>,
+        key: Option<&str>,
+        two_way_key: Option<&str>,
+        on_delete: Option<RelationMutate>,
+    ) -> Result<AttributeRelationship> {
+        validate_id(related_collection_id, "related_collection_id")?;
+
+        self.create_attribute(database_id, collection_id, "relationship", |params| {
+            params.insert("relatedCollectionId".to_string(), Value::String(related_collection_id.to_string()));
+            params.insert("type".to_string(), Value::String(relation_type.as_ref().to_string()));
+
+            if let Some(two_way) = two_way {
+                params.insert("twoWay".to_string(), Value::Bool(two_way));
+            }
+            if let Some(key) = key {
+                params.insert("key".to_string(), Value::String(key.to_string()));
+            }
+            if let Some(two_way_key) = two_way_key {
+                params.insert("twoWayKey".to_string(), Value::String(two_way_key.to_string()));
+            }
+            if let Some(on_delete) = on_delete {
+                params.insert("onDelete".to_string(), Value::String(on_delete.as_ref().to_string()));
+            }
+        }).await
+    }
+
+    /// Update a string attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_string_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<&str>,
+        size: Option<u32>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeString> {
+        self.update_attribute(database_id, collection_id, "string", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+
+            if let Some(size) = size {
+                params.insert("size".to_string(), Value::Number(size.into()));
+            }
+        }).await
+    }
+
+    /// Update an email attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_email_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<&str>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeEmail> {
+        self.update_attribute(database_id, collection_id, "email", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update an integer attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_integer_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        min: Option<i64>,
+        max: Option<i64>,
+        default: Option<i64>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeInteger> {
+        self.update_attribute(database_id, collection_id, "integer", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+
+            if let Some(min) = min {
+                params.insert("min".to_string(), Value::Number(min.into()));
+            }
+            if let Some(max) = max {
+                params.insert("max".to_string(), Value::Number(max.into()));
+            }
+        }).await
+    }
+
+    /// Update a float attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_float_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        min: Option<f64>,
+        max: Option<f64>,
+        default: Option<f64>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeFloat> {
+        self.update_attribute(database_id, collection_id, "float", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+
+            if let Some(min) = min {
+                params.insert("min".to_string(), Value::from(min));
+            }
+            if let Some(max) = max {
+                params.insert("max".to_string(), Value::from(max));
+            }
+        }).await
+    }
+
+    /// Update a boolean attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_boolean_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<bool>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeBoolean> {
+        self.update_attribute(database_id, collection_id, "boolean", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::Bool).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update a datetime attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_datetime_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<&str>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeDatetime> {
+        self.update_attribute(database_id, collection_id, "datetime", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update an enum attribute
+    ///
+    /// `elements` replaces the allowed values, and `default` the current
+    /// default, with `None` removing it. `new_key` renames the attribute.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_enum_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        elements: Vec<String>,
+        required: bool,
+        default: Option<&str>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeEnum> {
+        self.update_attribute(database_id, collection_id, "enum", key, new_key, |params| {
+            let elements_value: Vec<Value> = elements.into_iter().map(Value::String).collect();
+            params.insert("elements".to_string(), Value::Array(elements_value));
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update an IP address attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_ip_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<&str>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeIp> {
+        self.update_attribute(database_id, collection_id, "ip", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update a URL attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_url_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<&str>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeUrl> {
+        self.update_attribute(database_id, collection_id, "url", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update a point attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_point_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<Point>,
+        new_key: Option<&str>,
+    ) -> Result<AttributePoint> {
+        self.update_attribute(database_id, collection_id, "point", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update a line attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_line_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<Line>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeLine> {
+        self.update_attribute(database_id, collection_id, "line", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update a polygon attribute
+    ///
+    /// `default` replaces the current default, with `None` removing it.
+    /// `new_key` renames the attribute.
+    pub async fn update_polygon_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        required: bool,
+        default: Option<Polygon>,
+        new_key: Option<&str>,
+    ) -> Result<AttributePolygon> {
+        self.update_attribute(database_id, collection_id, "polygon", key, new_key, |params| {
+            params.insert("required".to_string(), Value::Bool(required));
+            params.insert("default".to_string(), default.map(Value::from).unwrap_or(Value::Null));
+        }).await
+    }
+
+    /// Update a relationship attribute
+    ///
+    /// `on_delete` changes what happens to related documents on delete, and
+    /// `new_key` renames the attribute.
+    pub async fn update_relationship_attribute(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        on_delete: Option<RelationMutate>,
+        new_key: Option<&str>,
+    ) -> Result<AttributeRelationship> {
+        if key.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("key"));
+        }
+
+        let path = Self::collection_path(database_id, collection_id, &format!("/attributes/{}/relationship", key))?;
+
+        let mut params = Map::new();
+        if let Some(on_delete) = on_delete {
+            params.insert("onDelete".to_string(), Value::String(on_delete.as_ref().to_string()));
+        }
+        if let Some(new_key) = new_key {
+            params.insert("newKey".to_string(), Value::String(new_key.to_string()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Create a document
+    ///
+    /// `data` is any value serializing to a JSON object, such as a struct
+    /// matching the collection's attributes. The created document is returned
+    /// with its attributes deserialized into the same type.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Databases};
+    /// use serde::{Deserialize, Serialize};
+    ///
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct Movie {
+    ///     title: String,
+    ///     year: u32,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let databases = Databases::new(&client);
+    ///     let movie = Movie { title: "Alien".to_string(), year: 1979 };
+    ///     let document = databases
+    ///         .create_document("database_id", "movies", "alien", &movie, None)
+    ///         .await?;
+    ///
+    ///     println!("{} was released in {}", document.data.title, document.data.year);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_document<T>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        data: &T,
+        permissions: Option<Vec<Permission>>,
+    ) -> Result<Document<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
+        validate_new_id(document_id, "document_id")?;
+
+        let mut params = Map::new();
+        params.insert("documentId".to_string(), Value::String(document_id.to_string()));
+        params.insert("data".to_string(), document_data(data)?);
+        
+        if let Some(permissions) = permissions {
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        let path = format!("/databases/{}/collections/{}/documents", database_id, collection_id);
+        
+        self.client
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Get a document
+    ///
+    /// The document attributes are deserialized into `T`, which callers must
+    /// name, such as `get_document::<serde_json::Value>` or a `Document<Movie>`
+    /// binding. Queries selecting a subset of the attributes need a `T` that
+    /// tolerates the missing ones.
+    pub async fn get_document<T: DeserializeOwned>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<Document<T>> {
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
+        validate_id(document_id, "document_id")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        let path = format!("/databases/{}/collections/{}/documents/{}", database_id, collection_id, document_id);
+        
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// Update a document
+    ///
+    /// Only the attributes present in `data` are changed, so `data` can be a
+    /// `serde_json::Value` or a struct holding some of the attributes, while
+    /// the updated document is deserialized into `T`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Databases};
+    /// use appwrite::models::Document;
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct Movie {
+    ///     title: String,
+    ///     year: u32,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let databases = Databases::new(&client);
+    ///     let movie: Document<Movie> = databases
+    ///         .update_document("database_id", "movies", "alien", Some(&json!({"year": 1979})), None)
+    ///         .await?;
+    ///     println!("{} was released in {}", movie.data.title, movie.data.year);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn update_document<T, U>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        data: Option<&U>,
+        permissions: Option<Vec<Permission>>,
+    ) -> Result<Document<T>>
+    where
+        T: DeserializeOwned,
+        U: Serialize,
+    {
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
+        validate_id(document_id, "document_id")?;
+
+        let mut params = Map::new();
+        
+        if let Some(data) = data {
+            params.insert("data".to_string(), document_data(data)?);
+        }
+        if let Some(permissions) = permissions {
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        let path = format!("/databases/{}/collections/{}/documents/{}", database_id, collection_id, document_id);
+        
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Delete a document
+    pub async fn delete_document(&self, database_id: &str, collection_id: &str, document_id: &str) -> Result<()> {
+        validate_id(document_id, "document_id")?;
+
+        let path = Self::collection_path(database_id, collection_id, &format!("/documents/{}", document_id))?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Atomically increase a numeric attribute of a document
+    ///
+    /// The server applies the change, so concurrent increments are never
+    /// lost. The request fails instead of exceeding `max`.
+    ///
+    /// # Arguments
+    /// * `attribute` - Key of the integer or float attribute
+    /// * `value` - Amount to add. Defaults to 1
+    /// * `max` - Upper bound the attribute may not exceed. Optional
+    ///
+    /// Amounts and bounds are sent exactly as given: integers such as
+    /// `Some(2.into())` for integer attributes, and
+    /// [`Number::from_f64`](serde_json::Number::from_f64) for float ones.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Databases};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let databases = Databases::new(&client);
+    ///     let post = databases
+    ///         .increment_document_attribute::<serde_json::Value>("database_id", "posts", "post_id", "views", None, None)
+    ///         .await?;
+    ///     println!("Views: {}", post.data["views"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn increment_document_attribute<T: DeserializeOwned>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        attribute: &str,
+        value: Option<Number>,
+        max: Option<Number>,
+    ) -> Result<Document<T>> {
+        self.update_document_attribute(database_id, collection_id, document_id, attribute, "increment", value, "max", max)
+            .await
+    }
+
+    /// Atomically decrease a numeric attribute of a document
+    ///
+    /// The server applies the change, so concurrent decrements are never
+    /// lost. The request fails instead of going below `min`, which makes this
+    /// suitable for reserving stock.
+    ///
+    /// # Arguments
+    /// * `attribute` - Key of the integer or float attribute
+    /// * `value` - Amount to subtract. Defaults to 1
+    /// * `min` - Lower bound the attribute may not go below. Optional
+    ///
+    /// Amounts and bounds are sent exactly as given, like
+    /// [`Databases::increment_document_attribute`].
+    pub async fn decrement_document_attribute<T: DeserializeOwned>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        attribute: &str,
+        value: Option<Number>,
+        min: Option<Number>,
+    ) -> Result<Document<T>> {
+        self.update_document_attribute(database_id, collection_id, document_id, attribute, "decrement", value, "min", min)
+            .await
+    }
+
+    /// Create many documents
+    ///
+    /// Documents are sent in batches of at most [`BULK_BATCH_SIZE`]. Batches
+    /// are written one after the other, so when one fails the documents of
+    /// the previous batches have already been created. They are returned in
+    /// an [`AppwriteError::BulkWrite`](crate::AppwriteError::BulkWrite) error
+    /// with the index of the failed batch, so the job can resume from there.
+    /// A failure in the first batch is returned as is.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Databases, Permission, Role};
+    /// use appwrite::models::Document;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let databases = Databases::new(&client);
+    ///     let documents = vec![
+    ///         Document::new("alien", json!({"title": "Alien", "year": 1979}))
+    ///             .set_permissions(vec![Permission::read(Role::any())]),
+    ///         Document::new("", json!({"title": "Aliens", "year": 1986})),
+    ///     ];
+    ///     let created = databases.create_documents("database_id", "movies", &documents).await?;
+    ///     println!("Created {} documents", created.total);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_documents<T>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        documents: &[Document<T>],
+    ) -> Result<DocumentList<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.write_documents("post", database_id, collection_id, documents)
+            .await
+    }
+
+    /// Create or replace many documents, matching existing ones by `$id`
+    ///
+    /// Documents are sent in batches like [`Databases::create_documents`].
+    /// Only their `$id`, `$permissions` and attributes are sent, so fetched
+    /// documents can be upserted as they are.
+    pub async fn upsert_documents<T>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        documents: &[Document<T>],
+    ) -> Result<DocumentList<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.write_documents("put", database_id, collection_id, documents)
+            .await
+    }
+
+    /// Update all documents matching the queries
+    ///
+    /// Only the attributes present in `data` are changed, and the updated
+    /// documents are deserialized into `T`. Without queries every document of
+    /// the collection is updated.
+    pub async fn update_documents<T, U>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        data: Option<&U>,
+        queries: Option<Vec<Query>>,
+    ) -> Result<DocumentList<T>>
+    where
+        T: DeserializeOwned,
+        U: Serialize,
+    {
+        let path = Self::collection_path(database_id, collection_id, "/documents")?;
+
+        let mut params = Map::new();
+        if let Some(data) = data {
+            params.insert("data".to_string(), document_data(data)?);
+        }
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Delete all documents matching the queries, returning the deleted documents
+    ///
+    /// Without queries every document of the collection is deleted.
+    pub async fn delete_documents(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<DocumentList> {
+        let path = Self::collection_path(database_id, collection_id, "/documents")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("delete", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// List documents
+    ///
+    /// The attributes of each document are deserialized into `T`, which callers
+    /// must name, such as `list_documents::<serde_json::Value>`.
+    pub async fn list_documents<T: DeserializeOwned>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<DocumentList<T>> {
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        let path = format!("/databases/{}/collections/{}/documents", database_id, collection_id);
+        
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// Stream all documents matching the queries, fetching them page by page
+    ///
+    /// Pages are requested with `cursorAfter` on the last document's `$id`,
+    /// keeping the filters and orderings in `queries`. A `limit` query sets
+    /// the page size, defaulting to
+    /// [`DEFAULT_PAGE_SIZE`](crate::pagination::DEFAULT_PAGE_SIZE), and a
+    /// `cursorAfter` query sets where the stream starts. `offset` and
+    /// `cursorBefore` queries are rejected.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Databases, Query};
+    /// use futures_util::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let databases = Databases::new(&client);
+    ///     let documents = databases.list_documents_stream::<serde_json::Value>(
+    ///         "database_id",
+    ///         "orders",
+    ///         Some(vec![Query::equal("status", "paid"), Query::limit(1000)]),
+    ///     );
+    ///     futures_util::pin_mut!(documents);
+    ///
+    ///     while let Some(document) = documents.try_next().await? {
+    ///         println!("{}", document.id);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn list_documents_stream<T>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> impl Stream<Item = Result<Document<T>>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        let databases = self.clone();
+        let database_id = database_id.to_string();
+        let collection_id = collection_id.to_string();
+
+        paginate(queries, move |queries| {
+            let databases = databases.clone();
+            let database_id = database_id.clone();
+            let collection_id = collection_id.clone();
+            async move {
+                databases
+                    .list_documents(&database_id, &collection_id, Some(queries))
+                    .await
+            }
+        })
+    }
+
+    /// Create an index
+    pub async fn create_index(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        key: &str,
+        index_type: IndexType,
+        attributes: Vec<String>,
+        orders: Option<Vec<String>>,
+    ) -> Result<Index> {
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
+        if key.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("key"));
+        }
+
+        let mut params = Map::new();
+        params.insert("key".to_string(), Value::String(key.to_string()));
+        params.insert("type".to_string(), Value::String(index_type.as_ref().to_string()));
         
         let attrs_value: Vec<Value> = attributes.into_iter().map(Value::String).collect();
         params.insert("attributes".to_string(), Value::Array(attrs_value));
@@ -372,27 +1468,223 @@ impl<'a> Databases<'a> {
         let path = format!("/databases/{}/collections/{}/indexes", database_id, collection_id);
         
         self.client
-            .call("post", &path, Some(headers), Some(params), None)
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// List the indexes of a collection
+    pub async fn list_indexes(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        queries: Option<Vec<Query>>,
+    ) -> Result<IndexList> {
+        let path = Self::collection_path(database_id, collection_id, "/indexes")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// Get an index by key
+    pub async fn get_index(&self, database_id: &str, collection_id: &str, key: &str) -> Result<Index> {
+        if key.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("key"));
+        }
+
+        let path = Self::collection_path(database_id, collection_id, &format!("/indexes/{}", key))?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Delete an index
+    pub async fn delete_index(&self, database_id: &str, collection_id: &str, key: &str) -> Result<()> {
+        if key.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("key"));
+        }
+
+        let path = Self::collection_path(database_id, collection_id, &format!("/indexes/{}", key))?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Start a transaction
+    ///
+    /// Returns a handle staging document operations on the new transaction.
+    /// See [`DatabaseTransaction`] and [`Databases::with_transaction`].
+    ///
+    /// # Arguments
+    /// * `ttl` - Seconds before an uncommitted transaction is rolled back. Optional
+    pub async fn transaction(&self, ttl: Option<u32>) -> Result<DatabaseTransaction<'a>> {
+        let transaction = self.create_transaction(ttl).await?;
+
+        Ok(DatabaseTransaction::new(self.clone(), transaction.id))
+    }
+
+    /// Run `f` in a transaction, committing it if `f` succeeds
+    ///
+    /// When `f` returns an error the transaction is rolled back and the error
+    /// is returned. If the rollback fails too, the transaction stays open
+    /// until its TTL expires; `f`'s error is still returned and the rollback
+    /// failure is printed as a warning with the transaction ID. When the
+    /// commit fails, its error is returned and nothing staged is applied.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{AppwriteError, Client, Databases};
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let databases = Databases::new(&client);
+    ///     databases
+    ///         .with_transaction(None, |transaction| async move {
+    ///             transaction
+    ///                 .create_document("shop", "orders", "order-1", &json!({"total": 30}), None)
+    ///                 .await?;
+    ///             transaction.delete_document("shop", "carts", "cart-1").await?;
+    ///             Ok::<_, AppwriteError>(())
+    ///         })
+    ///         .await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn with_transaction<F, Fut, R, E>(&self, ttl: Option<u32>, f: F) -> std::result::Result<R, E>
+    where
+        F: FnOnce(DatabaseTransaction<'a>) -> Fut,
+        Fut: Future<Output = std::result::Result<R, E>>,
+        E: From<crate::error::AppwriteError>,
+    {
+        let transaction = self.transaction(ttl).await?;
+
+        match f(transaction.clone()).await {
+            Ok(value) => {
+                transaction.commit().await?;
+                Ok(value)
+            }
+            Err(error) => {
+                if let Err(rollback_error) = transaction.rollback().await {
+                    self.client.warn(format_args!(
+                        "failed to roll back transaction {}, which stays open until its TTL expires: {}",
+                        transaction.id(),
+                        rollback_error
+                    ));
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Create a transaction
+    pub async fn create_transaction(&self, ttl: Option<u32>) -> Result<Transaction> {
+        let mut params = Map::new();
+        if let Some(ttl) = ttl {
+            params.insert("ttl".to_string(), Value::Number(ttl.into()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", "/databases/transactions", Some(headers), Some(params))
+            .await
+    }
+
+    /// List transactions
+    pub async fn list_transactions(&self, queries: Option<Vec<Query>>) -> Result<TransactionList> {
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        self.client
+            .call_as("get", "/databases/transactions", None, Some(params))
+            .await
+    }
+
+    /// Get a transaction
+    pub async fn get_transaction(&self, transaction_id: &str) -> Result<Transaction> {
+        let path = Self::transaction_path(transaction_id, "")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Commit or roll back a transaction
+    pub async fn update_transaction(&self, transaction_id: &str, commit: bool, rollback: bool) -> Result<Transaction> {
+        let path = Self::transaction_path(transaction_id, "")?;
+
+        let mut params = Map::new();
+        params.insert("commit".to_string(), Value::Bool(commit));
+        params.insert("rollback".to_string(), Value::Bool(rollback));
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Delete a transaction
+    pub async fn delete_transaction(&self, transaction_id: &str) -> Result<()> {
+        let path = Self::transaction_path(transaction_id, "")?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stage operations on a transaction
+    pub(crate) async fn create_operations(&self, transaction_id: &str, operations: Vec<Value>) -> Result<Transaction> {
+        let path = Self::transaction_path(transaction_id, "/operations")?;
+
+        let mut params = Map::new();
+        params.insert("operations".to_string(), Value::Array(operations));
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", &path, Some(headers), Some(params))
             .await
     }
 
     // Helper method for creating attributes
-    async fn create_attribute<F>(
+    async fn create_attribute<T, F>(
         &self,
         database_id: &str,
         collection_id: &str,
         attr_type: &str,
         param_builder: F,
-    ) -> Result<Value>
+    ) -> Result<T>
     where
+        T: DeserializeOwned,
         F: FnOnce(&mut Map<String, Value>),
     {
-        if database_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("database_id"));
-        }
-        if collection_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("collection_id"));
-        }
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
 
         let mut params = Map::new();
         param_builder(&mut params);
@@ -403,7 +1695,213 @@ impl<'a> Databases<'a> {
         let path = format!("/databases/{}/collections/{}/attributes/{}", database_id, collection_id, attr_type);
         
         self.client
-            .call("post", &path, Some(headers), Some(params), None)
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    // Helper method for creating point, line and polygon attributes
+    async fn create_spatial_attribute<T: DeserializeOwned>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        attr_type: &str,
+        key: &str,
+        required: bool,
+        default: Option<Value>,
+    ) -> Result<T> {
+        self.create_attribute(database_id, collection_id, attr_type, |params| {
+            params.insert("key".to_string(), Value::String(key.to_string()));
+            params.insert("required".to_string(), Value::Bool(required));
+
+            if let Some(default) = default {
+                params.insert("default".to_string(), default);
+            }
+        }).await
+    }
+
+    // Helper method for updating attributes
+    async fn update_attribute<T, F>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        attr_type: &str,
+        key: &str,
+        new_key: Option<&str>,
+        param_builder: F,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        F: FnOnce(&mut Map<String, Value>),
+    {
+        if key.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("key"));
+        }
+
+        let path = Self::collection_path(database_id, collection_id, &format!("/attributes/{}/{}", attr_type, key))?;
+
+        let mut params = Map::new();
+        param_builder(&mut params);
+
+        if let Some(new_key) = new_key {
+            params.insert("newKey".to_string(), Value::String(new_key.to_string()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
+    }
+
+    // Helper method for sending documents in batches
+    async fn write_documents<T>(
+        &self,
+        method: &str,
+        database_id: &str,
+        collection_id: &str,
+        documents: &[Document<T>],
+    ) -> Result<DocumentList<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let path = Self::collection_path(database_id, collection_id, "/documents")?;
+
+        // Batches are kept untyped so the documents written so far can be
+        // returned with the error of a failed batch
+        let mut written = DocumentList::default();
+        for (index, batch) in documents.chunks(BULK_BATCH_SIZE).enumerate() {
+            let result = async {
+                let batch_value = batch.iter().map(bulk_document).collect::<Result<Vec<Value>>>()?;
+
+                let mut params = Map::new();
+                params.insert("documents".to_string(), Value::Array(batch_value));
+
+                let mut headers = HashMap::new();
+                headers.insert("content-type".to_string(), "application/json".to_string());
+
+                self.client
+                    .call_as::<DocumentList>(method, &path, Some(headers), Some(params))
+                    .await
+            }
+            .await;
+
+            match result {
+                Ok(list) => {
+                    written.total += list.total;
+                    written.items.extend(list.items);
+                }
+                Err(error) if index == 0 => return Err(error),
+                Err(error) => {
+                    return Err(crate::error::AppwriteError::BulkWrite {
+                        written,
+                        failed_batch: index,
+                        source: Box::new(error),
+                    });
+                }
+            }
+        }
+
+        let items = written
+            .items
+            .into_iter()
+            .map(|document| {
+                Ok(Document {
+                    id: document.id,
+                    collection_id: document.collection_id,
+                    database_id: document.database_id,
+                    created_at: document.created_at,
+                    updated_at: document.updated_at,
+                    permissions: document.permissions,
+                    data: serde_json::from_value(Value::Object(document.data))?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DocumentList { total: written.total, items })
+    }
+
+    // Helper method for incrementing and decrementing document attributes
+    #[allow(clippy::too_many_arguments)]
+    async fn update_document_attribute<T: DeserializeOwned>(
+        &self,
+        database_id: &str,
+        collection_id: &str,
+        document_id: &str,
+        attribute: &str,
+        action: &str,
+        value: Option<Number>,
+        bound_key: &str,
+        bound: Option<Number>,
+    ) -> Result<Document<T>> {
+        validate_id(document_id, "document_id")?;
+        if attribute.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("attribute"));
+        }
+
+        let path = Self::collection_path(
+            database_id,
+            collection_id,
+            &format!("/documents/{}/{}/{}", document_id, attribute, action),
+        )?;
+
+        let mut params = Map::new();
+        if let Some(value) = value {
+            params.insert("value".to_string(), Value::Number(value));
+        }
+        if let Some(bound) = bound {
+            params.insert(bound_key.to_string(), Value::Number(bound));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
             .await
     }
-}
\ No newline at end of file
+
+    /// Build the path of a collection endpoint, such as `/databases/{id}/collections/{id}/indexes`
+    fn collection_path(database_id: &str, collection_id: &str, action: &str) -> Result<String> {
+        validate_id(database_id, "database_id")?;
+        validate_id(collection_id, "collection_id")?;
+
+        Ok(format!("/databases/{}/collections/{}{}", database_id, collection_id, action))
+    }
+
+    /// Build the path of a transaction endpoint, such as `/databases/transactions/{id}/operations`
+    fn transaction_path(transaction_id: &str, action: &str) -> Result<String> {
+        validate_id(transaction_id, "transaction_id")?;
+
+        Ok(format!("/databases/transactions/{}{}", transaction_id, action))
+    }
+}
+
+/// Serialize document attributes, which must form a JSON object
+pub(crate) fn document_data<T: Serialize>(data: &T) -> Result<Value> {
+    let data = serde_json::to_value(data)?;
+    if !data.is_object() {
+        return Err(crate::error::AppwriteError::invalid_parameter("data must be a JSON object"));
+    }
+
+    Ok(data)
+}
+
+/// Serialize a document sent in a bulk request
+///
+/// Only `$id`, `$permissions` and the attributes are sent, so documents
+/// fetched from the server can be written back without their system fields.
+fn bulk_document<T: Serialize>(document: &Document<T>) -> Result<Value> {
+    let mut data = document_data(&document.data)?;
+    if let Value::Object(object) = &mut data {
+        object.retain(|key, _| !key.starts_with('$'));
+        if !document.id.is_empty() {
+            object.insert("$id".to_string(), Value::String(document.id.clone()));
+        }
+        if !document.permissions.is_empty() {
+            object.insert("$permissions".to_string(), permissions_value(document.permissions.clone())?);
+        }
+    }
+
+    Ok(data)
+}
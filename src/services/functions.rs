@@ -6,6 +6,7 @@ use serde_json::Value;
 /// Functions service for serverless functions
 #[derive(Debug, Clone)]
 pub struct Functions<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
@@ -6,6 +6,7 @@ use serde_json::Value;
 /// GraphQL service
 #[derive(Debug, Clone)]
 pub struct GraphQL<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
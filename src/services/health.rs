@@ -6,6 +6,7 @@ use serde_json::Value;
 /// Health service for system health checks
 #[derive(Debug, Clone)]
 pub struct Health<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
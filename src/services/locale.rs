@@ -6,6 +6,7 @@ use serde_json::Value;
 /// Locale service for localization
 #[derive(Debug, Clone)]
 pub struct Locale<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
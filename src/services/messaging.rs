@@ -6,6 +6,7 @@ use serde_json::Value;
 /// Messaging service
 #[derive(Debug, Clone)]
 pub struct Messaging<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
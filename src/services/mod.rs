@@ -2,6 +2,7 @@
 
 pub mod account;
 pub mod avatars;
+pub mod database_transaction;
 pub mod databases;
 pub mod functions;
 pub mod graphql;
@@ -16,6 +17,7 @@ pub mod users;
 
 pub use account::Account;
 pub use avatars::Avatars;
+pub use database_transaction::DatabaseTransaction;
 pub use databases::Databases;
 pub use functions::Functions;
 pub use graphql::GraphQL;
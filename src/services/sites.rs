@@ -6,6 +6,7 @@ use serde_json::Value;
 /// Sites service for static site hosting
 #[derive(Debug, Clone)]
 pub struct Sites<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
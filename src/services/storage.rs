@@ -1,8 +1,21 @@
 //! Storage service for file operations
 
-use crate::{client::Client, error::Result, enums::*, input_file::InputFile};
+use crate::{
+    client::{Client, UploadProgress},
+    error::Result,
+    id::{validate_id, validate_new_id},
+    enums::*,
+    input_file::InputFile,
+    models::{Bucket, BucketList, File, FileList},
+    pagination::paginate,
+    permission::{permissions_value, Permission},
+    query::Query,
+};
+use bytes::Bytes;
+use futures_util::Stream;
 use serde_json::{Map, Value};
 use std::collections::HashMap;
+use tokio_util::sync::CancellationToken;
 
 /// Storage service for file operations
 #[derive(Debug, Clone)]
@@ -17,11 +30,11 @@ impl<'a> Storage<'a> {
     }
 
     /// List all storage buckets
-    pub async fn list_buckets(&self, queries: Option<Vec<String>>, search: Option<&str>) -> Result<Value> {
+    pub async fn list_buckets(&self, queries: Option<Vec<Query>>, search: Option<&str>) -> Result<BucketList> {
         let mut params = Map::new();
         
         if let Some(queries) = queries {
-            let queries_value: Vec<Value> = queries.into_iter().map(Value::String).collect();
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
             params.insert("queries".to_string(), Value::Array(queries_value));
         }
         if let Some(search) = search {
@@ -29,17 +42,36 @@ impl<'a> Storage<'a> {
         }
 
         self.client
-            .call("get", "/storage/buckets", None, Some(params), None)
+            .call_as("get", "/storage/buckets", None, Some(params))
             .await
     }
 
+    /// Stream all buckets matching the queries, fetching them page by page
+    ///
+    /// See [`Databases::list_documents_stream`](crate::Databases::list_documents_stream)
+    /// for how `queries` are handled.
+    pub fn list_buckets_stream(
+        &self,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> impl Stream<Item = Result<Bucket>> + 'a {
+        let storage = self.clone();
+        let search = search.map(str::to_string);
+
+        paginate(queries, move |queries| {
+            let storage = storage.clone();
+            let search = search.clone();
+            async move { storage.list_buckets(Some(queries), search.as_deref()).await }
+        })
+    }
+
     /// Create a new storage bucket
     #[allow(clippy::too_many_arguments)]
     pub async fn create_bucket(
         &self,
         bucket_id: &str,
         name: &str,
-        permissions: Option<Vec<String>>,
+        permissions: Option<Vec<Permission>>,
         file_security: Option<bool>,
         enabled: Option<bool>,
         maximum_file_size: Option<u64>,
@@ -47,10 +79,8 @@ impl<'a> Storage<'a> {
         compression: Option<Compression>,
         encryption: Option<bool>,
         antivirus: Option<bool>,
-    ) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
+    ) -> Result<Bucket> {
+        validate_new_id(bucket_id, "bucket_id")?;
         if name.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("name"));
         }
@@ -60,8 +90,7 @@ impl<'a> Storage<'a> {
         params.insert("name".to_string(), Value::String(name.to_string()));
         
         if let Some(permissions) = permissions {
-            let perms_value: Vec<Value> = permissions.into_iter().map(Value::String).collect();
-            params.insert("permissions".to_string(), Value::Array(perms_value));
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
         }
         if let Some(file_security) = file_security {
             params.insert("fileSecurity".to_string(), Value::Bool(file_security));
@@ -90,20 +119,18 @@ impl<'a> Storage<'a> {
         headers.insert("content-type".to_string(), "application/json".to_string());
 
         self.client
-            .call("post", "/storage/buckets", Some(headers), Some(params), None)
+            .call_as("post", "/storage/buckets", Some(headers), Some(params))
             .await
     }
 
     /// Get a bucket by ID
-    pub async fn get_bucket(&self, bucket_id: &str) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
+    pub async fn get_bucket(&self, bucket_id: &str) -> Result<Bucket> {
+        validate_id(bucket_id, "bucket_id")?;
 
         let path = format!("/storage/buckets/{}", bucket_id);
         
         self.client
-            .call("get", &path, None, None, None)
+            .call_as("get", &path, None, None)
             .await
     }
 
@@ -113,7 +140,7 @@ impl<'a> Storage<'a> {
         &self,
         bucket_id: &str,
         name: &str,
-        permissions: Option<Vec<String>>,
+        permissions: Option<Vec<Permission>>,
         file_security: Option<bool>,
         enabled: Option<bool>,
         maximum_file_size: Option<u64>,
@@ -121,10 +148,8 @@ impl<'a> Storage<'a> {
         compression: Option<Compression>,
         encryption: Option<bool>,
         antivirus: Option<bool>,
-    ) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
+    ) -> Result<Bucket> {
+        validate_id(bucket_id, "bucket_id")?;
         if name.is_empty() {
             return Err(crate::error::AppwriteError::missing_parameter("name"));
         }
@@ -133,8 +158,7 @@ impl<'a> Storage<'a> {
         params.insert("name".to_string(), Value::String(name.to_string()));
         
         if let Some(permissions) = permissions {
-            let perms_value: Vec<Value> = permissions.into_iter().map(Value::String).collect();
-            params.insert("permissions".to_string(), Value::Array(perms_value));
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
         }
         if let Some(file_security) = file_security {
             params.insert("fileSecurity".to_string(), Value::Bool(file_security));
@@ -165,60 +189,94 @@ impl<'a> Storage<'a> {
         let path = format!("/storage/buckets/{}", bucket_id);
         
         self.client
-            .call("put", &path, Some(headers), Some(params), None)
+            .call_as("put", &path, Some(headers), Some(params))
             .await
     }
 
     /// Create a file
+    ///
+    /// Files larger than the client's chunk size are uploaded in chunks. If a
+    /// previous upload with the same `file_id` was interrupted, calling this
+    /// again resumes after the last chunk the server acknowledged.
     pub async fn create_file(
         &self,
         bucket_id: &str,
         file_id: &str,
         file: InputFile,
-        permissions: Option<Vec<String>>,
-    ) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
-        if file_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("file_id"));
-        }
+        permissions: Option<Vec<Permission>>,
+    ) -> Result<File> {
+        self.create_file_with_progress(bucket_id, file_id, file, permissions, None, None)
+            .await
+    }
+
+    /// Create a file, reporting progress and honoring cancellation
+    ///
+    /// # Arguments
+    /// * `on_progress` - Called after every uploaded chunk
+    /// * `cancellation` - Aborts the upload with `AppwriteError::Cancelled` when cancelled
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Storage, InputFile, CancellationToken};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let storage = Storage::new(&client);
+    ///     let cancellation = CancellationToken::new();
+    ///     let file = storage.create_file_with_progress(
+    ///         "bucket_id",
+    ///         "file_id",
+    ///         InputFile::from_path("./video.mp4")?,
+    ///         None,
+    ///         Some(&mut |progress| println!("{:.1}%", progress.progress)),
+    ///         Some(&cancellation),
+    ///     ).await?;
+    ///     println!("Uploaded: {:?}", file);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_file_with_progress(
+        &self,
+        bucket_id: &str,
+        file_id: &str,
+        file: InputFile,
+        permissions: Option<Vec<Permission>>,
+        on_progress: Option<&mut (dyn FnMut(UploadProgress) + Send)>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<File> {
+        validate_id(bucket_id, "bucket_id")?;
+        validate_new_id(file_id, "file_id")?;
 
         let mut params = Map::new();
         params.insert("fileId".to_string(), Value::String(file_id.to_string()));
         
         if let Some(permissions) = permissions {
-            let perms_value: Vec<Value> = permissions.into_iter().map(Value::String).collect();
-            params.insert("permissions".to_string(), Value::Array(perms_value));
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
         }
 
-        // For now, we'll simulate file upload by adding file info to params
-        // In a real implementation, this would handle multipart/form-data
-        params.insert("file".to_string(), Value::String(format!("file:{}", file.filename())));
-
-        let mut headers = HashMap::new();
-        headers.insert("content-type".to_string(), "multipart/form-data".to_string());
-
         let path = format!("/storage/buckets/{}/files", bucket_id);
         
-        self.client
-            .call("post", &path, Some(headers), Some(params), None)
-            .await
+        let file = self.client
+            .upload(&path, None, Some(params), "file", &file, Some(file_id), on_progress, cancellation)
+            .await?;
+
+        Ok(serde_json::from_value(file)?)
     }
 
     /// Get a file
-    pub async fn get_file(&self, bucket_id: &str, file_id: &str) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
-        if file_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("file_id"));
-        }
+    pub async fn get_file(&self, bucket_id: &str, file_id: &str) -> Result<File> {
+        validate_id(bucket_id, "bucket_id")?;
+        validate_id(file_id, "file_id")?;
 
         let path = format!("/storage/buckets/{}/files/{}", bucket_id, file_id);
         
         self.client
-            .call("get", &path, None, None, None)
+            .call_as("get", &path, None, None)
             .await
     }
 
@@ -228,14 +286,10 @@ impl<'a> Storage<'a> {
         bucket_id: &str,
         file_id: &str,
         name: Option<&str>,
-        permissions: Option<Vec<String>>,
-    ) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
-        if file_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("file_id"));
-        }
+        permissions: Option<Vec<Permission>>,
+    ) -> Result<File> {
+        validate_id(bucket_id, "bucket_id")?;
+        validate_id(file_id, "file_id")?;
 
         let mut params = Map::new();
         
@@ -243,8 +297,7 @@ impl<'a> Storage<'a> {
             params.insert("name".to_string(), Value::String(name.to_string()));
         }
         if let Some(permissions) = permissions {
-            let perms_value: Vec<Value> = permissions.into_iter().map(Value::String).collect();
-            params.insert("permissions".to_string(), Value::Array(perms_value));
+            params.insert("permissions".to_string(), permissions_value(permissions)?);
         }
 
         let mut headers = HashMap::new();
@@ -253,7 +306,7 @@ impl<'a> Storage<'a> {
         let path = format!("/storage/buckets/{}/files/{}", bucket_id, file_id);
         
         self.client
-            .call("put", &path, Some(headers), Some(params), None)
+            .call_as("put", &path, Some(headers), Some(params))
             .await
     }
 
@@ -261,17 +314,15 @@ impl<'a> Storage<'a> {
     pub async fn list_files(
         &self,
         bucket_id: &str,
-        queries: Option<Vec<String>>,
+        queries: Option<Vec<Query>>,
         search: Option<&str>,
-    ) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
+    ) -> Result<FileList> {
+        validate_id(bucket_id, "bucket_id")?;
 
         let mut params = Map::new();
         
         if let Some(queries) = queries {
-            let queries_value: Vec<Value> = queries.into_iter().map(Value::String).collect();
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
             params.insert("queries".to_string(), Value::Array(queries_value));
         }
         if let Some(search) = search {
@@ -281,27 +332,89 @@ impl<'a> Storage<'a> {
         let path = format!("/storage/buckets/{}/files", bucket_id);
         
         self.client
-            .call("get", &path, None, Some(params), None)
+            .call_as("get", &path, None, Some(params))
             .await
     }
 
-    /// Get file download URL
-    pub async fn get_file_download(&self, bucket_id: &str, file_id: &str) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
-        if file_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("file_id"));
-        }
+    /// Stream all files in a bucket matching the queries, fetching them page by page
+    ///
+    /// See [`Databases::list_documents_stream`](crate::Databases::list_documents_stream)
+    /// for how `queries` are handled.
+    pub fn list_files_stream(
+        &self,
+        bucket_id: &str,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> impl Stream<Item = Result<File>> + 'a {
+        let storage = self.clone();
+        let bucket_id = bucket_id.to_string();
+        let search = search.map(str::to_string);
+
+        paginate(queries, move |queries| {
+            let storage = storage.clone();
+            let bucket_id = bucket_id.clone();
+            let search = search.clone();
+            async move {
+                storage
+                    .list_files(&bucket_id, Some(queries), search.as_deref())
+                    .await
+            }
+        })
+    }
 
-        let path = format!("/storage/buckets/{}/files/{}/download", bucket_id, file_id);
+    /// Download a file
+    ///
+    /// Returns the file contents. Use [`Storage::get_file_download_stream`] to
+    /// avoid buffering large files in memory.
+    pub async fn get_file_download(&self, bucket_id: &str, file_id: &str) -> Result<Bytes> {
+        let path = Self::file_path(bucket_id, file_id, "download")?;
         
         self.client
-            .call("get", &path, None, None, Some("location"))
+            .call_bytes("get", &path, None, None)
             .await
     }
 
-    /// Get file preview URL
+    /// Download a file as a stream of byte chunks
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Storage};
+    /// use futures_util::StreamExt;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let storage = Storage::new(&client);
+    ///     let mut stream = Box::pin(storage.get_file_download_stream("bucket_id", "file_id").await?);
+    ///     let mut output = tokio::fs::File::create("video.mp4").await?;
+    ///     while let Some(chunk) = stream.next().await {
+    ///         output.write_all(&chunk?).await?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_file_download_stream(
+        &self,
+        bucket_id: &str,
+        file_id: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let path = Self::file_path(bucket_id, file_id, "download")?;
+        
+        self.client
+            .call_stream("get", &path, None, None)
+            .await
+    }
+
+    /// Get a preview image of a file
+    ///
+    /// Returns the image contents, resized and transformed as requested. Use
+    /// [`Storage::get_file_preview_stream`] to avoid buffering large images in
+    /// memory.
     #[allow(clippy::too_many_arguments)]
     pub async fn get_file_preview(
         &self,
@@ -318,14 +431,88 @@ impl<'a> Storage<'a> {
         rotation: Option<i32>,
         background: Option<&str>,
         output: Option<ImageFormat>,
-    ) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
-        if file_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("file_id"));
-        }
+    ) -> Result<Bytes> {
+        let path = Self::file_path(bucket_id, file_id, "preview")?;
+        let params = Self::preview_params(
+            width, height, gravity, quality, border_width, border_color, border_radius, opacity,
+            rotation, background, output,
+        );
+
+        self.client
+            .call_bytes("get", &path, None, Some(params))
+            .await
+    }
 
+    /// Get a preview image of a file as a stream of byte chunks
+    ///
+    /// Takes the same transformations as [`Storage::get_file_preview`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_file_preview_stream(
+        &self,
+        bucket_id: &str,
+        file_id: &str,
+        width: Option<u32>,
+        height: Option<u32>,
+        gravity: Option<ImageGravity>,
+        quality: Option<u8>,
+        border_width: Option<u32>,
+        border_color: Option<&str>,
+        border_radius: Option<u32>,
+        opacity: Option<f32>,
+        rotation: Option<i32>,
+        background: Option<&str>,
+        output: Option<ImageFormat>,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let path = Self::file_path(bucket_id, file_id, "preview")?;
+        let params = Self::preview_params(
+            width, height, gravity, quality, border_width, border_color, border_radius, opacity,
+            rotation, background, output,
+        );
+
+        self.client
+            .call_stream("get", &path, None, Some(params))
+            .await
+    }
+
+    /// View a file
+    ///
+    /// Returns the file contents as served for inline display in a browser.
+    pub async fn get_file_view(&self, bucket_id: &str, file_id: &str) -> Result<Bytes> {
+        let path = Self::file_path(bucket_id, file_id, "view")?;
+        
+        self.client
+            .call_bytes("get", &path, None, None)
+            .await
+    }
+
+    /// View a file as a stream of byte chunks
+    pub async fn get_file_view_stream(
+        &self,
+        bucket_id: &str,
+        file_id: &str,
+    ) -> Result<impl Stream<Item = Result<Bytes>>> {
+        let path = Self::file_path(bucket_id, file_id, "view")?;
+        
+        self.client
+            .call_stream("get", &path, None, None)
+            .await
+    }
+
+    // Helper method for building preview transformation params
+    #[allow(clippy::too_many_arguments)]
+    fn preview_params(
+        width: Option<u32>,
+        height: Option<u32>,
+        gravity: Option<ImageGravity>,
+        quality: Option<u8>,
+        border_width: Option<u32>,
+        border_color: Option<&str>,
+        border_radius: Option<u32>,
+        opacity: Option<f32>,
+        rotation: Option<i32>,
+        background: Option<&str>,
+        output: Option<ImageFormat>,
+    ) -> Map<String, Value> {
         let mut params = Map::new();
         
         if let Some(width) = width {
@@ -362,26 +549,14 @@ impl<'a> Storage<'a> {
             params.insert("output".to_string(), Value::String(output.as_ref().to_string()));
         }
 
-        let path = format!("/storage/buckets/{}/files/{}/preview", bucket_id, file_id);
-        
-        self.client
-            .call("get", &path, None, Some(params), Some("location"))
-            .await
+        params
     }
 
-    /// Get file view URL
-    pub async fn get_file_view(&self, bucket_id: &str, file_id: &str) -> Result<Value> {
-        if bucket_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("bucket_id"));
-        }
-        if file_id.is_empty() {
-            return Err(crate::error::AppwriteError::missing_parameter("file_id"));
-        }
+    // Helper method for building file content paths
+    fn file_path(bucket_id: &str, file_id: &str, action: &str) -> Result<String> {
+        validate_id(bucket_id, "bucket_id")?;
+        validate_id(file_id, "file_id")?;
 
-        let path = format!("/storage/buckets/{}/files/{}/view", bucket_id, file_id);
-        
-        self.client
-            .call("get", &path, None, None, Some("location"))
-            .await
+        Ok(format!("/storage/buckets/{}/files/{}/{}", bucket_id, file_id, action))
     }
-}
\ No newline at end of file
+}
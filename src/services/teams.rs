@@ -1,9 +1,21 @@
 //! Teams service for team management
 
-use crate::{client::Client, error::Result};
-use serde_json::Value;
+use crate::{
+    client::Client,
+    error::Result,
+    models::{Membership, MembershipList, Preferences, Team, TeamList},
+    pagination::paginate,
+    query::Query,
+};
+use futures_util::Stream;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 
 /// Teams service for team management
+///
+/// Team members get the `team:{team_id}` role, and `team:{team_id}/{role}` for
+/// each of their roles in the team. Grant access to them with `Role::team` and
+/// `Role::team_with_role`.
 #[derive(Debug, Clone)]
 pub struct Teams<'a> {
     client: &'a Client,
@@ -16,8 +28,351 @@ impl<'a> Teams<'a> {
     }
 
     /// List teams
-    pub async fn list(&self) -> Result<Value> {
-        // Implementation would go here
-        todo!("Teams service implementation")
+    pub async fn list(&self, queries: Option<Vec<Query>>, search: Option<&str>) -> Result<TeamList> {
+        let mut params = Map::new();
+
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+        if let Some(search) = search {
+            params.insert("search".to_string(), Value::String(search.to_string()));
+        }
+
+        self.client
+            .call_as("get", "/teams", None, Some(params))
+            .await
+    }
+
+    /// Stream all teams matching the queries, fetching them page by page
+    ///
+    /// See [`Databases::list_documents_stream`](crate::Databases::list_documents_stream)
+    /// for how `queries` are handled.
+    pub fn list_stream(
+        &self,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> impl Stream<Item = Result<Team>> + 'a {
+        let teams = self.clone();
+        let search = search.map(str::to_string);
+
+        paginate(queries, move |queries| {
+            let teams = teams.clone();
+            let search = search.clone();
+            async move { teams.list(Some(queries), search.as_deref()).await }
+        })
+    }
+
+    /// Create a new team
+    ///
+    /// # Arguments
+    /// * `team_id` - Team ID. Choose a custom ID or generate a random ID
+    /// * `name` - Team name
+    /// * `roles` - Roles given to the creator of the team. Defaults to `["owner"]`
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Teams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let teams = Teams::new(&client);
+    ///     let team = teams.create("acme", "Acme Inc.", None).await?;
+    ///     println!("Created team: {:?}", team);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create(&self, team_id: &str, name: &str, roles: Option<Vec<String>>) -> Result<Team> {
+        if team_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("team_id"));
+        }
+        if name.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("name"));
+        }
+
+        let mut params = Map::new();
+        params.insert("teamId".to_string(), Value::String(team_id.to_string()));
+        params.insert("name".to_string(), Value::String(name.to_string()));
+
+        if let Some(roles) = roles {
+            let roles_value: Vec<Value> = roles.into_iter().map(Value::String).collect();
+            params.insert("roles".to_string(), Value::Array(roles_value));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", "/teams", Some(headers), Some(params))
+            .await
+    }
+
+    /// Get a team
+    pub async fn get(&self, team_id: &str) -> Result<Team> {
+        let path = Self::team_path(team_id, "")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Update a team's name
+    pub async fn update_name(&self, team_id: &str, name: &str) -> Result<Team> {
+        if name.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("name"));
+        }
+
+        let path = Self::team_path(team_id, "")?;
+
+        let mut params = Map::new();
+        params.insert("name".to_string(), Value::String(name.to_string()));
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("put", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Delete a team and all its memberships
+    pub async fn delete(&self, team_id: &str) -> Result<()> {
+        let path = Self::team_path(team_id, "")?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get a team's shared preferences
+    pub async fn get_prefs(&self, team_id: &str) -> Result<Preferences> {
+        let path = Self::team_path(team_id, "/prefs")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Replace a team's shared preferences
+    pub async fn update_prefs(&self, team_id: &str, prefs: Value) -> Result<Preferences> {
+        let path = Self::team_path(team_id, "/prefs")?;
+
+        let mut params = Map::new();
+        params.insert("prefs".to_string(), prefs);
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("put", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// List a team's memberships
+    pub async fn list_memberships(
+        &self,
+        team_id: &str,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> Result<MembershipList> {
+        let path = Self::team_path(team_id, "/memberships")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+        if let Some(search) = search {
+            params.insert("search".to_string(), Value::String(search.to_string()));
+        }
+
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// Invite a user to a team
+    ///
+    /// The user is identified by one of `email`, `user_id` or `phone`. Users
+    /// invited by email without an account get one created for them. With an
+    /// API key the membership is confirmed at once, otherwise the user is sent
+    /// an invitation linking to `url`, where the app should call
+    /// [`Teams::update_membership_status`].
+    ///
+    /// # Arguments
+    /// * `team_id` - Team ID
+    /// * `roles` - Roles of the user in the team
+    /// * `email` - Email address of the user. Optional
+    /// * `user_id` - ID of the user. Optional
+    /// * `phone` - Phone number of the user in E.164 format. Optional
+    /// * `url` - URL the invitation redirects to. Optional
+    /// * `name` - Name of the user. Optional
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Teams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id");
+    ///
+    ///     let teams = Teams::new(&client);
+    ///     let membership = teams.create_membership(
+    ///         "acme",
+    ///         vec!["developer".to_string()],
+    ///         Some("jane@example.com"),
+    ///         None,
+    ///         None,
+    ///         Some("https://example.com/join"),
+    ///         Some("Jane Doe"),
+    ///     ).await?;
+    ///     println!("Invited: {:?}", membership);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_membership(
+        &self,
+        team_id: &str,
+        roles: Vec<String>,
+        email: Option<&str>,
+        user_id: Option<&str>,
+        phone: Option<&str>,
+        url: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<Membership> {
+        let path = Self::team_path(team_id, "/memberships")?;
+
+        if email.is_none() && user_id.is_none() && phone.is_none() {
+            return Err(crate::error::AppwriteError::missing_parameter("email, user_id or phone"));
+        }
+
+        let mut params = Map::new();
+        let roles_value: Vec<Value> = roles.into_iter().map(Value::String).collect();
+        params.insert("roles".to_string(), Value::Array(roles_value));
+
+        if let Some(email) = email {
+            params.insert("email".to_string(), Value::String(email.to_string()));
+        }
+        if let Some(user_id) = user_id {
+            params.insert("userId".to_string(), Value::String(user_id.to_string()));
+        }
+        if let Some(phone) = phone {
+            params.insert("phone".to_string(), Value::String(phone.to_string()));
+        }
+        if let Some(url) = url {
+            params.insert("url".to_string(), Value::String(url.to_string()));
+        }
+        if let Some(name) = name {
+            params.insert("name".to_string(), Value::String(name.to_string()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Get a membership
+    pub async fn get_membership(&self, team_id: &str, membership_id: &str) -> Result<Membership> {
+        let path = Self::membership_path(team_id, membership_id, "")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Replace the roles of a team member
+    pub async fn update_membership(
+        &self,
+        team_id: &str,
+        membership_id: &str,
+        roles: Vec<String>,
+    ) -> Result<Membership> {
+        let path = Self::membership_path(team_id, membership_id, "")?;
+
+        let mut params = Map::new();
+        let roles_value: Vec<Value> = roles.into_iter().map(Value::String).collect();
+        params.insert("roles".to_string(), Value::Array(roles_value));
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
     }
-}
\ No newline at end of file
+
+    /// Remove a member from a team, or cancel a pending invitation
+    pub async fn delete_membership(&self, team_id: &str, membership_id: &str) -> Result<()> {
+        let path = Self::membership_path(team_id, membership_id, "")?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Accept a team invitation
+    ///
+    /// Call this from the page the invitation `url` points to, with the
+    /// `membershipId`, `userId` and `secret` query parameters of the link. The
+    /// user is logged in if they were not already.
+    pub async fn update_membership_status(
+        &self,
+        team_id: &str,
+        membership_id: &str,
+        user_id: &str,
+        secret: &str,
+    ) -> Result<Membership> {
+        let path = Self::membership_path(team_id, membership_id, "/status")?;
+
+        if user_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("user_id"));
+        }
+        if secret.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("secret"));
+        }
+
+        let mut params = Map::new();
+        params.insert("userId".to_string(), Value::String(user_id.to_string()));
+        params.insert("secret".to_string(), Value::String(secret.to_string()));
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Build the path of a team endpoint, such as `/teams/{id}/prefs`
+    fn team_path(team_id: &str, action: &str) -> Result<String> {
+        if team_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("team_id"));
+        }
+
+        Ok(format!("/teams/{}{}", team_id, action))
+    }
+
+    /// Build the path of a membership endpoint, such as `/teams/{id}/memberships/{id}/status`
+    fn membership_path(team_id: &str, membership_id: &str, action: &str) -> Result<String> {
+        if membership_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("membership_id"));
+        }
+
+        Self::team_path(team_id, &format!("/memberships/{}{}", membership_id, action))
+    }
+}
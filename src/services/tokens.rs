@@ -6,6 +6,7 @@ use serde_json::Value;
 /// Tokens service
 #[derive(Debug, Clone)]
 pub struct Tokens<'a> {
+    #[allow(dead_code)]
     client: &'a Client,
 }
 
@@ -1,9 +1,24 @@
 //! Users service for user management
 
-use crate::{client::Client, error::Result};
-use serde_json::Value;
+use crate::{
+    client::Client,
+    error::Result,
+    models::{
+        IdentityList, Jwt, LogList, MembershipList, Preferences, Session, SessionList,
+        TargetList, Token, User, UserList,
+    },
+    pagination::paginate,
+    query::Query,
+    password_hash::PasswordHash,
+};
+use futures_util::Stream;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
 
 /// Users service for user management
+///
+/// Manages the users of a project with server privileges, so the client
+/// needs an API key with the `users.read` and `users.write` scopes.
 #[derive(Debug, Clone)]
 pub struct Users<'a> {
     client: &'a Client,
@@ -16,8 +31,508 @@ impl<'a> Users<'a> {
     }
 
     /// List users
-    pub async fn list(&self) -> Result<Value> {
-        // Implementation would go here
-        todo!("Users service implementation")
+    pub async fn list(&self, queries: Option<Vec<Query>>, search: Option<&str>) -> Result<UserList> {
+        let mut params = Map::new();
+
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+        if let Some(search) = search {
+            params.insert("search".to_string(), Value::String(search.to_string()));
+        }
+
+        self.client
+            .call_as("get", "/users", None, Some(params))
+            .await
+    }
+
+    /// Stream all users matching the queries, fetching them page by page
+    ///
+    /// See [`Databases::list_documents_stream`](crate::Databases::list_documents_stream)
+    /// for how `queries` are handled.
+    pub fn list_stream(
+        &self,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> impl Stream<Item = Result<User>> + 'a {
+        let users = self.clone();
+        let search = search.map(str::to_string);
+
+        paginate(queries, move |queries| {
+            let users = users.clone();
+            let search = search.clone();
+            async move { users.list(Some(queries), search.as_deref()).await }
+        })
+    }
+
+    /// Create a new user
+    ///
+    /// # Arguments
+    /// * `user_id` - User ID. Choose a custom ID or generate a random ID
+    /// * `email` - User email. Optional
+    /// * `phone` - User phone number in E.164 format. Optional
+    /// * `password` - Plain text user password. Must be at least 8 chars. Optional
+    /// * `name` - User name. Optional
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, Users};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let users = Users::new(&client);
+    ///     let user = users.create(
+    ///         "unique_user_id",
+    ///         Some("user@example.com"),
+    ///         None,
+    ///         Some("securepassword"),
+    ///         Some("John Doe"),
+    ///     ).await?;
+    ///     println!("Created user: {:?}", user);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create(
+        &self,
+        user_id: &str,
+        email: Option<&str>,
+        phone: Option<&str>,
+        password: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<User> {
+        if user_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("user_id"));
+        }
+
+        let mut params = Map::new();
+        params.insert("userId".to_string(), Value::String(user_id.to_string()));
+
+        if let Some(email) = email {
+            params.insert("email".to_string(), Value::String(email.to_string()));
+        }
+        if let Some(phone) = phone {
+            params.insert("phone".to_string(), Value::String(phone.to_string()));
+        }
+        if let Some(password) = password {
+            params.insert("password".to_string(), Value::String(password.to_string()));
+        }
+        if let Some(name) = name {
+            params.insert("name".to_string(), Value::String(name.to_string()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", "/users", Some(headers), Some(params))
+            .await
+    }
+
+    /// Create a new user with a password hash imported from another system
+    ///
+    /// # Arguments
+    /// * `user_id` - User ID. Choose a custom ID or generate a random ID
+    /// * `email` - User email
+    /// * `hash` - Password hash and the options of the algorithm that produced it
+    /// * `name` - User name. Optional
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use appwrite::{Client, PasswordHash, Users};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new()
+    ///         .set_endpoint("https://cloud.appwrite.io/v1")?
+    ///         .set_project("your-project-id")
+    ///         .set_key("your-api-key");
+    ///
+    ///     let users = Users::new(&client);
+    ///     let user = users.create_with_hash(
+    ///         "unique_user_id",
+    ///         "user@example.com",
+    ///         PasswordHash::Bcrypt {
+    ///             hash: "$2a$10$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy".to_string(),
+    ///         },
+    ///         Some("John Doe"),
+    ///     ).await?;
+    ///     println!("Imported user: {:?}", user);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_with_hash(
+        &self,
+        user_id: &str,
+        email: &str,
+        hash: PasswordHash,
+        name: Option<&str>,
+    ) -> Result<User> {
+        if user_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("user_id"));
+        }
+        if email.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("email"));
+        }
+        if hash.hash().is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("hash"));
+        }
+
+        let mut params = Map::new();
+        params.insert("userId".to_string(), Value::String(user_id.to_string()));
+        params.insert("email".to_string(), Value::String(email.to_string()));
+        hash.insert_params(&mut params);
+
+        if let Some(name) = name {
+            params.insert("name".to_string(), Value::String(name.to_string()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        let path = format!("/users/{}", hash.algorithm());
+
+        self.client
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Get a user
+    pub async fn get(&self, user_id: &str) -> Result<User> {
+        let path = Self::user_path(user_id, "")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Delete a user
+    ///
+    /// The user ID can be reused once the user is deleted. Use
+    /// [`Users::update_status`] to block a user instead.
+    pub async fn delete(&self, user_id: &str) -> Result<()> {
+        let path = Self::user_path(user_id, "")?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Update a user's email address
+    pub async fn update_email(&self, user_id: &str, email: &str) -> Result<User> {
+        if email.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("email"));
+        }
+
+        self.update(user_id, "/email", "email", Value::String(email.to_string()))
+            .await
     }
-}
\ No newline at end of file
+
+    /// Update a user's name
+    pub async fn update_name(&self, user_id: &str, name: &str) -> Result<User> {
+        if name.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("name"));
+        }
+
+        self.update(user_id, "/name", "name", Value::String(name.to_string()))
+            .await
+    }
+
+    /// Update a user's phone number
+    ///
+    /// # Arguments
+    /// * `number` - Phone number in E.164 format, such as `+12065550100`
+    pub async fn update_phone(&self, user_id: &str, number: &str) -> Result<User> {
+        if number.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("number"));
+        }
+
+        self.update(user_id, "/phone", "number", Value::String(number.to_string()))
+            .await
+    }
+
+    /// Update a user's password
+    pub async fn update_password(&self, user_id: &str, password: &str) -> Result<User> {
+        if password.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("password"));
+        }
+
+        self.update(user_id, "/password", "password", Value::String(password.to_string()))
+            .await
+    }
+
+    /// Enable or block a user
+    ///
+    /// Blocked users cannot sign in or use their existing sessions.
+    pub async fn update_status(&self, user_id: &str, status: bool) -> Result<User> {
+        self.update(user_id, "/status", "status", Value::Bool(status))
+            .await
+    }
+
+    /// Replace a user's labels
+    ///
+    /// Labels can be used to grant permissions with `Role::label`.
+    pub async fn update_labels(&self, user_id: &str, labels: Vec<String>) -> Result<User> {
+        let path = Self::user_path(user_id, "/labels")?;
+
+        let mut params = Map::new();
+        let labels_value: Vec<Value> = labels.into_iter().map(Value::String).collect();
+        params.insert("labels".to_string(), Value::Array(labels_value));
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("put", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Get a user's preferences
+    pub async fn get_prefs(&self, user_id: &str) -> Result<Preferences> {
+        let path = Self::user_path(user_id, "/prefs")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Replace a user's preferences
+    pub async fn update_prefs(&self, user_id: &str, prefs: Value) -> Result<Preferences> {
+        self.update(user_id, "/prefs", "prefs", prefs)
+            .await
+    }
+
+    /// Mark a user's email address as verified or unverified
+    pub async fn update_email_verification(&self, user_id: &str, email_verification: bool) -> Result<User> {
+        self.update(user_id, "/verification", "emailVerification", Value::Bool(email_verification))
+            .await
+    }
+
+    /// Mark a user's phone number as verified or unverified
+    pub async fn update_phone_verification(&self, user_id: &str, phone_verification: bool) -> Result<User> {
+        self.update(user_id, "/verification/phone", "phoneVerification", Value::Bool(phone_verification))
+            .await
+    }
+
+    /// List a user's sessions
+    pub async fn list_sessions(&self, user_id: &str) -> Result<SessionList> {
+        let path = Self::user_path(user_id, "/sessions")?;
+
+        self.client
+            .call_as("get", &path, None, None)
+            .await
+    }
+
+    /// Create a session for a user
+    ///
+    /// The session secret can be used by a server to act on behalf of the user.
+    pub async fn create_session(&self, user_id: &str) -> Result<Session> {
+        let path = Self::user_path(user_id, "/sessions")?;
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", &path, Some(headers), None)
+            .await
+    }
+
+    /// Delete all of a user's sessions
+    pub async fn delete_sessions(&self, user_id: &str) -> Result<()> {
+        let path = Self::user_path(user_id, "/sessions")?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete one of a user's sessions
+    pub async fn delete_session(&self, user_id: &str, session_id: &str) -> Result<()> {
+        if session_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("session_id"));
+        }
+
+        let path = Self::user_path(user_id, &format!("/sessions/{}", session_id))?;
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// List a user's activity logs
+    pub async fn list_logs(&self, user_id: &str, queries: Option<Vec<Query>>) -> Result<LogList> {
+        let path = Self::user_path(user_id, "/logs")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// List a user's team memberships
+    pub async fn list_memberships(
+        &self,
+        user_id: &str,
+        queries: Option<Vec<Query>>,
+        search: Option<&str>,
+    ) -> Result<MembershipList> {
+        let path = Self::user_path(user_id, "/memberships")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+        if let Some(search) = search {
+            params.insert("search".to_string(), Value::String(search.to_string()));
+        }
+
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// List the OAuth2 identities of all users
+    ///
+    /// Filter with `Query::equal("userId", ...)` to get the identities of one user.
+    pub async fn list_identities(&self, queries: Option<Vec<Query>>, search: Option<&str>) -> Result<IdentityList> {
+        let mut params = Map::new();
+
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+        if let Some(search) = search {
+            params.insert("search".to_string(), Value::String(search.to_string()));
+        }
+
+        self.client
+            .call_as("get", "/users/identities", None, Some(params))
+            .await
+    }
+
+    /// Delete an OAuth2 identity
+    pub async fn delete_identity(&self, identity_id: &str) -> Result<()> {
+        if identity_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("identity_id"));
+        }
+
+        let path = format!("/users/identities/{}", identity_id);
+
+        self.client
+            .call("delete", &path, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// List a user's messaging targets
+    pub async fn list_targets(&self, user_id: &str, queries: Option<Vec<Query>>) -> Result<TargetList> {
+        let path = Self::user_path(user_id, "/targets")?;
+
+        let mut params = Map::new();
+        if let Some(queries) = queries {
+            let queries_value: Vec<Value> = queries.iter().map(|query| Value::String(query.to_string())).collect();
+            params.insert("queries".to_string(), Value::Array(queries_value));
+        }
+
+        self.client
+            .call_as("get", &path, None, Some(params))
+            .await
+    }
+
+    /// Create a JWT to act as a user
+    ///
+    /// # Arguments
+    /// * `session_id` - Session to create the JWT for. Use `"recent"` for the
+    ///   most recent session. Defaults to the most recent session
+    /// * `duration` - Seconds until the JWT expires, at most 3600. Defaults to 900
+    pub async fn create_jwt(&self, user_id: &str, session_id: Option<&str>, duration: Option<u32>) -> Result<Jwt> {
+        let path = Self::user_path(user_id, "/jwts")?;
+
+        let mut params = Map::new();
+        if let Some(session_id) = session_id {
+            params.insert("sessionId".to_string(), Value::String(session_id.to_string()));
+        }
+        if let Some(duration) = duration {
+            params.insert("duration".to_string(), Value::Number(duration.into()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Create a token a user can exchange for a session
+    ///
+    /// # Arguments
+    /// * `length` - Length of the token secret, between 4 and 128. Defaults to 6
+    /// * `expire` - Seconds until the token expires, at least 60. Defaults to 900
+    pub async fn create_token(&self, user_id: &str, length: Option<u32>, expire: Option<u32>) -> Result<Token> {
+        let path = Self::user_path(user_id, "/tokens")?;
+
+        let mut params = Map::new();
+        if let Some(length) = length {
+            params.insert("length".to_string(), Value::Number(length.into()));
+        }
+        if let Some(expire) = expire {
+            params.insert("expire".to_string(), Value::Number(expire.into()));
+        }
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("post", &path, Some(headers), Some(params))
+            .await
+    }
+
+    // Helper method for updating a single user field
+    async fn update<T: serde::de::DeserializeOwned>(
+        &self,
+        user_id: &str,
+        action: &str,
+        key: &str,
+        value: Value,
+    ) -> Result<T> {
+        let path = Self::user_path(user_id, action)?;
+
+        let mut params = Map::new();
+        params.insert(key.to_string(), value);
+
+        let mut headers = HashMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        self.client
+            .call_as("patch", &path, Some(headers), Some(params))
+            .await
+    }
+
+    /// Build the path of a user endpoint, such as `/users/{id}/prefs`
+    fn user_path(user_id: &str, action: &str) -> Result<String> {
+        if user_id.is_empty() {
+            return Err(crate::error::AppwriteError::missing_parameter("user_id"));
+        }
+
+        Ok(format!("/users/{}{}", user_id, action))
+    }
+}
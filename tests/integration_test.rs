@@ -18,7 +18,7 @@ async fn test_client_creation() {
 
 #[test]
 fn test_query_builder() {
-    let query = Query::equal("status", "active");
+    let query = Query::equal("status", "active").to_string();
     assert!(query.contains("equal"));
     assert!(query.contains("status"));
     assert!(query.contains("active"));
@@ -27,7 +27,8 @@ fn test_query_builder() {
         Query::greater_than("age", 18),
         Query::less_than("age", 65),
     ]);
-    assert!(complex_query.contains("and"));
+    assert!(complex_query.to_string().contains("and"));
+    assert_eq!(complex_query.queries.len(), 2);
 }
 
 #[test]
@@ -49,7 +50,7 @@ fn test_roles() {
     assert_eq!(Role::guests(), "guests");
     assert_eq!(Role::user("123"), "user:123");
     assert_eq!(Role::team("team-123"), "team:team-123");
-    assert_eq!(Role::team_with_role("team-123", "admin"), "team:team-123:admin");
+    assert_eq!(Role::team_with_role("team-123", "admin"), "team:team-123/admin");
 }
 
 #[test]
@@ -68,4 +69,1072 @@ fn test_error_types() {
 
     let missing_param_error = AppwriteError::missing_parameter("user_id");
     assert!(missing_param_error.to_string().contains("user_id"));
-}
\ No newline at end of file
+}
+
+/// Spawn a loopback HTTP server that answers each request with the next canned
+/// response and hands back the raw requests it received.
+async fn mock_server(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+    mock_server_with_headers(responses.into_iter().map(|(status, body)| (status, "", body)).collect()).await
+}
+
+/// Like `mock_server`, with extra raw header lines for each response
+async fn mock_server_with_headers(
+    responses: Vec<(u16, &'static str, &'static str)>,
+) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}/v1", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for (status, headers, body) in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut raw = Vec::new();
+            let mut buf = [0u8; 8192];
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                raw.extend_from_slice(&buf[..n]);
+                let text = String::from_utf8_lossy(&raw);
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let length = text[..header_end]
+                        .lines()
+                        .find_map(|l| l.to_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                        .unwrap_or(0);
+                    if raw.len() >= header_end + 4 + length {
+                        break;
+                    }
+                }
+                if n == 0 {
+                    break;
+                }
+            }
+            let response = format!(
+                "HTTP/1.1 {} OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n{}\r\n{}",
+                status,
+                body.len(),
+                headers,
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            requests.push(String::from_utf8_lossy(&raw).into_owned());
+        }
+        requests
+    });
+
+    (endpoint, handle)
+}
+
+#[tokio::test]
+async fn test_create_file_sends_file_contents() {
+    use appwrite::InputFile;
+
+    let (endpoint, server) = mock_server(vec![(201, r#"{"$id":"file-1"}"#)]).await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_project("test-project");
+    let storage = Storage::new(&client);
+
+    let file = InputFile::from_bytes(
+        b"hello appwrite".to_vec(),
+        "hello.txt",
+        Some("text/plain".to_string()),
+    );
+    let result = storage
+        .create_file("bucket-1", "file-1", file, Some(vec![Permission::read(Role::any())]))
+        .await
+        .unwrap();
+    assert_eq!(result.id, "file-1");
+
+    let requests = server.await.unwrap();
+    let request = &requests[0];
+    assert!(request.starts_with("POST /v1/storage/buckets/bucket-1/files"));
+    assert!(request.contains("multipart/form-data; boundary="));
+    assert!(request.contains(r#"name="file"; filename="hello.txt""#));
+    assert!(request.contains("Content-Type: text/plain"));
+    assert!(request.contains("hello appwrite"));
+    assert!(request.contains(r#"name="permissions[0]""#));
+}
+
+#[tokio::test]
+async fn test_create_file_uploads_in_chunks() {
+    use appwrite::InputFile;
+
+    let (endpoint, server) = mock_server(vec![
+        (404, r#"{"message":"File not found","code":404}"#),
+        (201, r#"{"$id":"file-1","chunksUploaded":1}"#),
+        (201, r#"{"$id":"file-1","chunksUploaded":2}"#),
+        (201, r#"{"$id":"file-1","chunksUploaded":3}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_chunk_size(5);
+    let storage = Storage::new(&client);
+
+    let file = InputFile::from_bytes(b"abcdefghijkl".to_vec(), "letters.txt", None);
+    let result = storage.create_file("bucket-1", "file-1", file, None).await.unwrap();
+    assert_eq!(result.chunks_uploaded, 3);
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("GET /v1/storage/buckets/bucket-1/files/file-1"));
+    assert!(requests[1].contains("content-range: bytes 0-4/12"));
+    assert!(requests[1].contains("abcde"));
+    assert!(!requests[1].contains("x-appwrite-id"));
+    assert!(requests[2].contains("content-range: bytes 5-9/12"));
+    assert!(requests[2].contains("x-appwrite-id: file-1"));
+    assert!(requests[3].contains("content-range: bytes 10-11/12"));
+    assert!(requests[3].contains("kl\r\n"));
+}
+
+#[tokio::test]
+async fn test_create_file_resumes_after_acknowledged_chunks() {
+    use appwrite::InputFile;
+
+    let (endpoint, server) = mock_server(vec![
+        (200, r#"{"$id":"file-1","name":"letters.txt","sizeOriginal":12,"chunksUploaded":2,"chunksTotal":3}"#),
+        (201, r#"{"$id":"file-1","chunksUploaded":3,"chunksTotal":3}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_chunk_size(5);
+    let storage = Storage::new(&client);
+
+    let file = InputFile::from_bytes(b"abcdefghijkl".to_vec(), "letters.txt", None);
+    let result = storage.create_file("bucket-1", "file-1", file, None).await.unwrap();
+    assert_eq!(result.chunks_uploaded, 3);
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[1].contains("content-range: bytes 10-11/12"));
+    assert!(requests[1].contains("x-appwrite-id: file-1"));
+}
+
+#[tokio::test]
+async fn test_create_file_does_not_resume_over_errors_or_other_files() {
+    use appwrite::InputFile;
+
+    let (endpoint, server) = mock_server(vec![
+        (401, r#"{"message":"Unauthorized","code":401,"type":"user_unauthorized"}"#),
+        (200, r#"{"$id":"file-1","name":"other.txt","sizeOriginal":12,"chunksUploaded":3,"chunksTotal":3}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_chunk_size(5);
+    let storage = Storage::new(&client);
+
+    let file = InputFile::from_bytes(b"abcdefghijkl".to_vec(), "letters.txt", None);
+    let error = storage.create_file("bucket-1", "file-1", file.clone(), None).await.unwrap_err();
+    assert_eq!(error.code(), Some(401));
+
+    let error = storage.create_file("bucket-1", "file-1", file, None).await.unwrap_err();
+    assert_eq!(error.code(), Some(409));
+    assert_eq!(error.error_type(), Some("storage_file_already_exists"));
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests.iter().all(|r| r.starts_with("GET ")));
+}
+
+#[tokio::test]
+async fn test_create_file_reports_progress() {
+    use appwrite::{InputFile, UploadProgress};
+
+    let (endpoint, server) = mock_server(vec![
+        (404, r#"{"message":"File not found","code":404}"#),
+        (201, r#"{"$id":"file-1"}"#),
+        (201, r#"{"$id":"file-1"}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_chunk_size(6);
+    let storage = Storage::new(&client);
+
+    let mut updates: Vec<UploadProgress> = Vec::new();
+    let file = InputFile::from_bytes(b"abcdefghij".to_vec(), "letters.txt", None);
+    storage
+        .create_file_with_progress(
+            "bucket-1",
+            "file-1",
+            file,
+            None,
+            Some(&mut |progress| updates.push(progress)),
+            None,
+        )
+        .await
+        .unwrap();
+    server.await.unwrap();
+
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].id, "file-1");
+    assert_eq!(updates[0].size_uploaded, 6);
+    assert_eq!(updates[0].chunks_uploaded, 1);
+    assert_eq!(updates[1].size_uploaded, 10);
+    assert_eq!(updates[1].size_total, 10);
+    assert_eq!(updates[1].chunks_total, 2);
+    assert_eq!(updates[1].progress, 100.0);
+}
+
+#[tokio::test]
+async fn test_create_file_stops_when_cancelled() {
+    use appwrite::{AppwriteError, CancellationToken, InputFile};
+
+    let client = Client::new().set_endpoint("http://127.0.0.1:9/v1").unwrap().set_chunk_size(4);
+    let storage = Storage::new(&client);
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+
+    let file = InputFile::from_bytes(b"abcdefghij".to_vec(), "letters.txt", None);
+    let result = storage
+        .create_file_with_progress("bucket-1", "unique()", file, None, None, Some(&cancellation))
+        .await;
+    assert!(matches!(result, Err(AppwriteError::Cancelled)));
+}
+
+#[tokio::test]
+async fn test_get_file_download_returns_contents() {
+    use futures_util::StreamExt;
+
+    let (endpoint, server) = mock_server(vec![
+        (200, "raw file contents"),
+        (200, "streamed contents"),
+        (404, r#"{"message":"File not found","code":404,"type":"storage_file_not_found"}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let storage = Storage::new(&client);
+
+    let contents = storage.get_file_download("bucket-1", "file-1").await.unwrap();
+    assert_eq!(&contents[..], b"raw file contents");
+
+    let mut stream = Box::pin(storage.get_file_view_stream("bucket-1", "file-1").await.unwrap());
+    let mut streamed = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        streamed.extend_from_slice(&chunk.unwrap());
+    }
+    assert_eq!(streamed, b"streamed contents");
+
+    let error = storage.get_file_download("bucket-1", "missing").await.unwrap_err();
+    assert_eq!(error.code(), Some(404));
+    assert_eq!(error.error_type(), Some("storage_file_not_found"));
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("GET /v1/storage/buckets/bucket-1/files/file-1/download"));
+    assert!(requests[1].starts_with("GET /v1/storage/buckets/bucket-1/files/file-1/view"));
+}
+
+#[tokio::test]
+async fn test_get_file_preview_stream() {
+    use appwrite::enums::ImageFormat;
+    use futures_util::StreamExt;
+
+    let (endpoint, server) = mock_server(vec![(200, "preview bytes")]).await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let storage = Storage::new(&client);
+
+    let mut stream = Box::pin(
+        storage
+            .get_file_preview_stream(
+                "bucket-1", "file-1", Some(64), None, None, None, None, None, None, None, None, None,
+                Some(ImageFormat::Png),
+            )
+            .await
+            .unwrap(),
+    );
+    let mut streamed = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        streamed.extend_from_slice(&chunk.unwrap());
+    }
+    assert_eq!(streamed, b"preview bytes");
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("GET /v1/storage/buckets/bucket-1/files/file-1/preview?"));
+    assert!(requests[0].contains("width=64"));
+    assert!(requests[0].contains("output=png"));
+}
+
+#[tokio::test]
+async fn test_create_oauth2_session_returns_redirect_url() {
+    use appwrite::{Account, OAuthProvider};
+
+    let client = Client::new()
+        .set_endpoint("https://appwrite.example.com/v1")
+        .unwrap()
+        .set_project("test-project");
+    let account = Account::new(&client);
+
+    let url = account
+        .create_oauth2_session(
+            OAuthProvider::Github,
+            Some("https://app.example.com/ok"),
+            None,
+            Some(vec!["repo".to_string()]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        url,
+        "https://appwrite.example.com/v1/account/sessions/oauth2/github?project=test-project\
+         &scopes%5B0%5D=repo&success=https%3A%2F%2Fapp.example.com%2Fok"
+    );
+}
+
+#[tokio::test]
+async fn test_tls_configuration() {
+    use appwrite::AppwriteError;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // A server with a self-signed certificate, answering each connection that
+    // completes the handshake
+    let identity = native_tls::Identity::from_pkcs8(
+        include_bytes!("fixtures/self_signed.pem"),
+        include_bytes!("fixtures/self_signed.key"),
+    )
+    .unwrap();
+    let acceptor = tokio_native_tls::TlsAcceptor::from(native_tls::TlsAcceptor::new(identity).unwrap());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("https://localhost:{}/v1", listener.local_addr().unwrap().port());
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let Ok(mut stream) = acceptor.accept(socket).await else {
+                continue;
+            };
+            let mut buf = [0u8; 8192];
+            let _ = stream.read(&mut buf).await;
+            let body = r#"{"status":"pass"}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes()).await;
+            let _ = stream.shutdown().await;
+        }
+    });
+
+    let client = Client::new().set_endpoint(&endpoint).unwrap().set_self_signed(true).unwrap();
+    let result = client.call("get", "/health", None, None).await.unwrap();
+    assert_eq!(result["status"], "pass");
+
+    let client = client.set_self_signed(false).unwrap();
+    let error = client.call("get", "/health", None, None).await.unwrap_err();
+    assert!(matches!(error, AppwriteError::Http(_)));
+
+    let error = Client::new().add_root_certificate("not a certificate").unwrap_err();
+    assert!(matches!(error, AppwriteError::InvalidParameter(_)));
+
+    let error = Client::new()
+        .set_client_certificate("not a certificate", "not a key")
+        .unwrap_err();
+    assert!(matches!(error, AppwriteError::InvalidParameter(_)));
+}
+
+#[tokio::test]
+async fn test_transient_errors_are_retried() {
+    use appwrite::RetryPolicy;
+    use std::time::Duration;
+
+    let (endpoint, server) = mock_server(vec![
+        (503, r#"{"message":"Service unavailable","code":503}"#),
+        (429, r#"{"message":"Rate limit exceeded","code":429}"#),
+        (200, r#"{"$id":"bucket-1"}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_retry_policy(
+        RetryPolicy::new()
+            .set_max_attempts(3)
+            .set_initial_backoff(Duration::from_millis(1)),
+    );
+
+    let bucket = Storage::new(&client).get_bucket("bucket-1").await.unwrap();
+    assert_eq!(bucket.id, "bucket-1");
+    assert_eq!(server.await.unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_server_errors_back_off_despite_rate_limit_reset() {
+    use appwrite::RetryPolicy;
+    use std::time::{Duration, Instant};
+
+    // Rate limited endpoints send a reset time even when the limit is not hit
+    let headers = "x-ratelimit-limit: 10\r\nx-ratelimit-remaining: 9\r\nx-ratelimit-reset: 4102444800\r\n";
+    let (endpoint, server) = mock_server_with_headers(vec![
+        (503, headers, r#"{"message":"Service unavailable","code":503}"#),
+        (200, headers, r#"{"$id":"bucket-1"}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_retry_policy(
+        RetryPolicy::new()
+            .set_initial_backoff(Duration::from_millis(1))
+            .set_jitter(false),
+    );
+
+    let start = Instant::now();
+    let bucket = Storage::new(&client).get_bucket("bucket-1").await.unwrap();
+    assert_eq!(bucket.id, "bucket-1");
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(server.await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_non_idempotent_requests_are_not_retried() {
+    use appwrite::RetryPolicy;
+
+    let (endpoint, server) = mock_server(vec![(503, r#"{"message":"Service unavailable","code":503}"#)]).await;
+    let client = Client::new()
+        .set_endpoint(endpoint)
+        .unwrap()
+        .set_retry_policy(RetryPolicy::new());
+
+    let error = Databases::new(&client)
+        .create("db-1", "Database", None)
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), Some(503));
+    assert_eq!(server.await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_failed_chunk_is_retried_alone() {
+    use appwrite::{InputFile, RetryPolicy};
+    use std::time::Duration;
+
+    let (endpoint, server) = mock_server(vec![
+        (404, r#"{"message":"File not found","code":404}"#),
+        (201, r#"{"$id":"file-1"}"#),
+        (500, r#"{"message":"Server error","code":500}"#),
+        (201, r#"{"$id":"file-1"}"#),
+    ])
+    .await;
+    let client = Client::new()
+        .set_endpoint(endpoint)
+        .unwrap()
+        .set_chunk_size(5)
+        .set_retry_policy(RetryPolicy::new().set_initial_backoff(Duration::from_millis(1)));
+
+    let file = InputFile::from_bytes(b"abcdefghij".to_vec(), "letters.txt", None);
+    Storage::new(&client).create_file("bucket-1", "file-1", file, None).await.unwrap();
+
+    let requests = server.await.unwrap();
+    assert!(requests[1].contains("content-range: bytes 0-4/10"));
+    assert!(requests[2].contains("content-range: bytes 5-9/10"));
+    assert!(requests[3].contains("content-range: bytes 5-9/10"));
+}
+
+#[tokio::test]
+async fn test_rate_limit_headers_are_exposed() {
+    use appwrite::RateLimit;
+
+    let headers = "x-ratelimit-limit: 10\r\nx-ratelimit-remaining: 0\r\nx-ratelimit-reset: 1700000000\r\n";
+    let (endpoint, server) = mock_server_with_headers(vec![
+        (200, "x-ratelimit-limit: 10\r\nx-ratelimit-remaining: 1\r\nx-ratelimit-reset: 1700000000\r\n", r#"{"$id":"bucket-1"}"#),
+        (429, headers, r#"{"message":"Rate limit exceeded","code":429,"type":"general_rate_limit_exceeded"}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let storage = Storage::new(&client);
+    assert_eq!(client.rate_limit(), None);
+
+    storage.get_bucket("bucket-1").await.unwrap();
+    assert_eq!(client.rate_limit().map(|r| r.remaining), Some(1));
+
+    let error = storage.get_bucket("bucket-1").await.unwrap_err();
+    let expected = RateLimit { limit: 10, remaining: 0, reset: 1700000000 };
+    assert_eq!(error.code(), Some(429));
+    assert_eq!(error.rate_limit(), Some(expected));
+    assert_eq!(client.rate_limit(), Some(expected));
+    server.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_documents_are_typed() {
+    use appwrite::models::Document;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Movie {
+        title: String,
+        year: u32,
+    }
+
+    #[derive(Serialize)]
+    struct MovieYear {
+        year: u32,
+    }
+
+    let (endpoint, server) = mock_server(vec![
+        (201, r#"{"$id":"alien","$collectionId":"movies","$databaseId":"main","title":"Alien","year":1979}"#),
+        (200, r#"{"total":1,"documents":[{"$id":"alien","title":"Alien","year":1979}]}"#),
+        (200, r#"{"$id":"alien","title":"Alien","year":1980}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let movie = Movie { title: "Alien".to_string(), year: 1979 };
+    let document = databases
+        .create_document("main", "movies", "alien", &movie, None)
+        .await
+        .unwrap();
+    assert_eq!(document.id, "alien");
+    assert_eq!(document.collection_id, "movies");
+    assert_eq!(document.data, movie);
+
+    let movies = databases.list_documents::<Movie>("main", "movies", None).await.unwrap();
+    assert_eq!(movies.total, 1);
+    assert_eq!(movies.items[0].data.title, "Alien");
+
+    // A partial update sends only the given attributes and returns the whole document
+    let updated: Document<Movie> = databases
+        .update_document("main", "movies", "alien", Some(&MovieYear { year: 1980 }), None)
+        .await
+        .unwrap();
+    assert_eq!(updated.data.title, "Alien");
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].contains(r#""data":{"title":"Alien","year":1979}"#));
+    assert!(requests[2].contains(r#"{"data":{"year":1980}}"#));
+
+    let error = databases
+        .create_document("main", "movies", "alien", &"not an object".to_string(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, appwrite::AppwriteError::InvalidParameter(_)));
+}
+
+#[tokio::test]
+async fn test_list_documents_stream_pages_with_cursor() {
+    use futures_util::TryStreamExt;
+
+    let (endpoint, server) = mock_server(vec![
+        (200, r#"{"total":3,"documents":[{"$id":"a"},{"$id":"b"}]}"#),
+        (200, r#"{"total":3,"documents":[{"$id":"c"}]}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let ids: Vec<String> = databases
+        .list_documents_stream::<serde_json::Value>(
+            "main",
+            "orders",
+            Some(vec![Query::equal("status", "paid"), Query::limit(2)]),
+        )
+        .map_ok(|document| document.id)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(ids, vec!["a", "b", "c"]);
+
+    let requests = server.await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].contains("status"));
+    assert!(!requests[0].contains("cursorAfter"));
+    assert!(requests[1].contains("status"));
+    assert!(requests[1].contains("cursorAfter"));
+}
+
+#[tokio::test]
+async fn test_users_service() {
+    use appwrite::Users;
+
+    let (endpoint, server) = mock_server(vec![
+        (201, r#"{"$id":"user-1","email":"user@example.com","status":true}"#),
+        (200, r#"{"$id":"user-1","labels":["admin"]}"#),
+        (200, r#"{"theme":"dark"}"#),
+        (201, r#"{"jwt":"header.payload.signature"}"#),
+        (204, ""),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_key("secret");
+    let users = Users::new(&client);
+
+    let user = users
+        .create("user-1", Some("user@example.com"), None, Some("password"), None)
+        .await
+        .unwrap();
+    assert_eq!(user.id, "user-1");
+    assert!(user.status);
+
+    let user = users.update_labels("user-1", vec!["admin".to_string()]).await.unwrap();
+    assert_eq!(user.labels, vec!["admin"]);
+
+    let prefs = users.update_prefs("user-1", serde_json::json!({"theme": "dark"})).await.unwrap();
+    assert_eq!(prefs["theme"], "dark");
+
+    let jwt = users.create_jwt("user-1", None, Some(60)).await.unwrap();
+    assert_eq!(jwt.jwt, "header.payload.signature");
+
+    users.delete("user-1").await.unwrap();
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("POST /v1/users "));
+    assert!(!requests[0].contains("phone"));
+    assert!(requests[1].starts_with("PUT /v1/users/user-1/labels "));
+    assert!(requests[1].contains(r#""labels":["admin"]"#));
+    assert!(requests[2].starts_with("PATCH /v1/users/user-1/prefs "));
+    assert!(requests[3].contains(r#""duration":60"#));
+    assert!(requests[4].starts_with("DELETE /v1/users/user-1 "));
+
+    assert!(matches!(users.get("").await, Err(appwrite::AppwriteError::MissingParameter(_))));
+}
+
+#[tokio::test]
+async fn test_users_create_with_imported_hash() {
+    use appwrite::{PasswordHash, ShaVersion, Users};
+
+    let (endpoint, server) = mock_server(vec![
+        (201, r#"{"$id":"user-1","hash":"scrypt"}"#),
+        (201, r#"{"$id":"user-2","hash":"sha"}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap().set_key("secret");
+    let users = Users::new(&client);
+
+    let hash = PasswordHash::Scrypt {
+        hash: "aGFzaA==".to_string(),
+        salt: "c2FsdA==".to_string(),
+        cpu: 16384,
+        memory: 8,
+        parallel: 1,
+        length: 64,
+    };
+    let user = users.create_with_hash("user-1", "one@example.com", hash, None).await.unwrap();
+    assert_eq!(user.hash.as_deref(), Some("scrypt"));
+
+    let hash = PasswordHash::Sha { hash: "abc".to_string(), version: Some(ShaVersion::Sha1) };
+    users.create_with_hash("user-2", "two@example.com", hash, Some("Two")).await.unwrap();
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("POST /v1/users/scrypt "));
+    assert!(requests[0].contains(r#""passwordCpu":16384"#));
+    assert!(requests[0].contains(r#""passwordSalt":"c2FsdA==""#));
+    assert!(requests[1].starts_with("POST /v1/users/sha "));
+    assert!(requests[1].contains(r#""passwordVersion":"sha1""#));
+}
+
+#[tokio::test]
+async fn test_teams_service() {
+    use appwrite::Teams;
+
+    let (endpoint, server) = mock_server(vec![
+        (201, r#"{"$id":"acme","name":"Acme","total":1}"#),
+        (201, r#"{"$id":"m-1","teamId":"acme","userEmail":"jane@example.com","roles":["developer"]}"#),
+        (200, r#"{"$id":"m-1","teamId":"acme","confirm":true,"roles":["developer"]}"#),
+        (200, r#"{"total":1,"memberships":[{"$id":"m-1","roles":["admin"]}]}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let teams = Teams::new(&client);
+
+    let team = teams.create("acme", "Acme", None).await.unwrap();
+    assert_eq!(team.total, 1);
+
+    let membership = teams
+        .create_membership(
+            "acme",
+            vec!["developer".to_string()],
+            Some("jane@example.com"),
+            None,
+            None,
+            Some("https://example.com/join"),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(membership.roles, vec!["developer"]);
+
+    let membership = teams
+        .update_membership_status("acme", "m-1", "user-1", "secret")
+        .await
+        .unwrap();
+    assert!(membership.confirm);
+
+    let memberships = teams.list_memberships("acme", None, None).await.unwrap();
+    assert_eq!(memberships.items[0].roles, vec!["admin"]);
+
+    let requests = server.await.unwrap();
+    assert!(requests[1].starts_with("POST /v1/teams/acme/memberships "));
+    assert!(requests[1].contains(r#""roles":["developer"]"#));
+    assert!(requests[2].starts_with("PATCH /v1/teams/acme/memberships/m-1/status "));
+    assert!(requests[2].contains(r#""secret":"secret""#));
+
+    let error = teams
+        .create_membership("acme", vec![], None, None, None, None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, appwrite::AppwriteError::MissingParameter(_)));
+}
+
+#[tokio::test]
+async fn test_database_schema_lifecycle() {
+    use appwrite::models::Attribute;
+
+    let (endpoint, server) = mock_server(vec![
+        (200, r#"{"$id":"movies","name":"Films","documentSecurity":true,"enabled":false}"#),
+        (200, r#"{"total":2,"attributes":[{"key":"title","type":"string","size":255},{"key":"year","type":"integer"}]}"#),
+        (200, r#"{"key":"by_year","type":"key","status":"available","attributes":["year"]}"#),
+        (204, ""),
+        (204, ""),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let collection = databases
+        .update_collection("main", "movies", "Films", None, Some(true), Some(false))
+        .await
+        .unwrap();
+    assert!(collection.document_security);
+    assert!(!collection.enabled);
+
+    let attributes = databases.list_attributes("main", "movies", None).await.unwrap();
+    assert!(matches!(attributes.items[0], Attribute::String(_)));
+    assert!(matches!(attributes.items[1], Attribute::Integer(_)));
+
+    let index = databases.get_index("main", "movies", "by_year").await.unwrap();
+    assert_eq!(index.attributes, vec!["year"]);
+
+    databases.delete_attribute("main", "movies", "year").await.unwrap();
+    databases.delete("main").await.unwrap();
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("PUT /v1/databases/main/collections/movies "));
+    assert!(requests[0].contains(r#""documentSecurity":true"#));
+    assert!(requests[2].starts_with("GET /v1/databases/main/collections/movies/indexes/by_year "));
+    assert!(requests[3].starts_with("DELETE /v1/databases/main/collections/movies/attributes/year "));
+    assert!(requests[4].starts_with("DELETE /v1/databases/main "));
+}
+
+#[tokio::test]
+async fn test_relationship_and_attribute_updates() {
+    use appwrite::{RelationMutate, RelationshipType};
+
+    let (endpoint, server) = mock_server(vec![
+        (202, r#"{"key":"books","type":"relationship","relatedCollection":"books","relationType":"oneToMany","twoWay":true,"twoWayKey":"author","onDelete":"cascade","side":"parent"}"#),
+        (200, r#"{"key":"rating","type":"double","required":false,"min":0.0,"max":5.0}"#),
+        (200, r#"{"key":"status","type":"string","format":"enum","elements":["draft","published"]}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let relationship = databases
+        .create_relationship_attribute(
+            "main",
+            "authors",
+            "books",
+            RelationshipType::OneToMany,
+            Some(true),
+            Some("books"),
+            Some("author"),
+            Some(RelationMutate::Cascade),
+        )
+        .await
+        .unwrap();
+    assert_eq!(relationship.relation_type, RelationshipType::OneToMany);
+    assert_eq!(relationship.on_delete, RelationMutate::Cascade);
+
+    let rating = databases
+        .update_float_attribute("main", "books", "rating", false, Some(0.0), Some(5.0), None, None)
+        .await
+        .unwrap();
+    assert_eq!(rating.max, Some(5.0));
+
+    let status = databases
+        .update_enum_attribute(
+            "main",
+            "books",
+            "status",
+            vec!["draft".to_string(), "published".to_string()],
+            false,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(status.elements, vec!["draft", "published"]);
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("POST /v1/databases/main/collections/authors/attributes/relationship "));
+    assert!(requests[0].contains(r#""onDelete":"cascade""#));
+    assert!(requests[0].contains(r#""type":"oneToMany""#));
+    assert!(requests[1].starts_with("PATCH /v1/databases/main/collections/books/attributes/float/rating "));
+    assert!(requests[1].contains(r#""default":null"#));
+    assert!(requests[2].contains(r#""elements":["draft","published"]"#));
+}
+
+#[tokio::test]
+async fn test_bulk_documents_are_sent_in_batches() {
+    use appwrite::models::Document;
+    use appwrite::services::databases::BULK_BATCH_SIZE;
+
+    let (endpoint, server) = mock_server(vec![
+        (201, r#"{"total":100,"documents":[{"$id":"doc-0","n":0}]}"#),
+        (201, r#"{"total":50,"documents":[{"$id":"doc-100","n":100}]}"#),
+        (200, r#"{"total":2,"documents":[{"$id":"doc-0"},{"$id":"doc-1"}]}"#),
+        (204, ""),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let documents: Vec<Document<serde_json::Value>> = (0..BULK_BATCH_SIZE + 50)
+        .map(|n| Document::new(format!("doc-{}", n), serde_json::json!({ "n": n })))
+        .collect();
+    let created = databases.create_documents("main", "numbers", &documents).await.unwrap();
+    assert_eq!(created.total, 150);
+    assert_eq!(created.items.len(), 2);
+    assert_eq!(created.items[1].data["n"], 100);
+
+    let deleted = databases
+        .delete_documents("main", "numbers", Some(vec![Query::less_than("n", 2)]))
+        .await
+        .unwrap();
+    assert_eq!(deleted.total, 2);
+
+    databases.delete_document("main", "numbers", "doc-2").await.unwrap();
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("POST /v1/databases/main/collections/numbers/documents "));
+    assert!(requests[0].contains(r#"{"$id":"doc-99","n":99}"#));
+    assert!(!requests[0].contains(r#""doc-100""#));
+    assert!(requests[1].contains(r#"{"$id":"doc-149","n":149}"#));
+    assert!(requests[2].starts_with("DELETE /v1/databases/main/collections/numbers/documents "));
+    assert!(requests[2].contains("lessThan"));
+    assert!(requests[3].starts_with("DELETE /v1/databases/main/collections/numbers/documents/doc-2 "));
+}
+
+#[tokio::test]
+async fn test_fetched_documents_are_upserted_without_system_fields() {
+    let (endpoint, server) = mock_server(vec![
+        (
+            200,
+            r#"{"total":1,"documents":[{"$id":"doc-1","$sequence":7,"$collectionId":"numbers","$databaseId":"main","$createdAt":"2024-01-01T00:00:00.000+00:00","$updatedAt":"2024-01-01T00:00:00.000+00:00","$permissions":["read(\"any\")"],"n":1}]}"#,
+        ),
+        (200, r#"{"total":1,"documents":[{"$id":"doc-1","n":2}]}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let mut documents = databases
+        .list_documents::<serde_json::Map<String, serde_json::Value>>("main", "numbers", None)
+        .await
+        .unwrap()
+        .items;
+    documents[0].data.insert("n".to_string(), serde_json::json!(2));
+    databases.upsert_documents("main", "numbers", &documents).await.unwrap();
+
+    let requests = server.await.unwrap();
+    let body = requests[1].split("\r\n\r\n").nth(1).unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(body).unwrap(),
+        serde_json::json!({"documents": [{"$id": "doc-1", "$permissions": ["read(\"any\")"], "n": 2}]})
+    );
+}
+
+#[tokio::test]
+async fn test_failed_bulk_batch_returns_written_documents() {
+    use appwrite::models::Document;
+    use appwrite::services::databases::BULK_BATCH_SIZE;
+    use appwrite::AppwriteError;
+
+    let (endpoint, server) = mock_server(vec![
+        (201, r#"{"total":100,"documents":[{"$id":"doc-0","n":0}]}"#),
+        (409, r#"{"message":"Document already exists","code":409,"type":"document_already_exists"}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let documents: Vec<Document<serde_json::Value>> = (0..BULK_BATCH_SIZE * 3)
+        .map(|n| Document::new(format!("doc-{}", n), serde_json::json!({ "n": n })))
+        .collect();
+    let error = databases.create_documents("main", "numbers", &documents).await.unwrap_err();
+    assert_eq!(error.code(), Some(409));
+    match error {
+        AppwriteError::BulkWrite { written, failed_batch, .. } => {
+            assert_eq!(failed_batch, 1);
+            assert_eq!(written.total, 100);
+            assert_eq!(written.items[0].id, "doc-0");
+        }
+        other => panic!("unexpected error: {}", other),
+    }
+
+    assert_eq!(server.await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_increment_and_decrement_document_attribute() {
+    let (endpoint, server) = mock_server(vec![
+        (200, r#"{"$id":"post-1","views":11}"#),
+        (200, r#"{"$id":"item-1","stock":0}"#),
+        (200, r#"{"$id":"account-1","balance":10.5}"#),
+        (200, r#"{"$id":"ledger-1","entries":9007199254740993}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let post = databases
+        .increment_document_attribute::<serde_json::Value>("main", "posts", "post-1", "views", None, None)
+        .await
+        .unwrap();
+    assert_eq!(post.data["views"], 11);
+
+    let item = databases
+        .decrement_document_attribute::<serde_json::Value>("main", "items", "item-1", "stock", Some(2.into()), Some(0.into()))
+        .await
+        .unwrap();
+    assert_eq!(item.data["stock"], 0);
+
+    databases
+        .increment_document_attribute::<serde_json::Value>("main", "accounts", "account-1", "balance", serde_json::Number::from_f64(0.5), None)
+        .await
+        .unwrap();
+
+    // Integers beyond the exact range of f64 are kept as they are
+    databases
+        .increment_document_attribute::<serde_json::Value>(
+            "main",
+            "ledgers",
+            "ledger-1",
+            "entries",
+            Some(9_007_199_254_740_993u64.into()),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("PATCH /v1/databases/main/collections/posts/documents/post-1/views/increment "));
+    assert!(requests[0].ends_with("{}"));
+    assert!(requests[1].starts_with("PATCH /v1/databases/main/collections/items/documents/item-1/stock/decrement "));
+    assert!(requests[1].ends_with(r#"{"min":0,"value":2}"#));
+    assert!(requests[2].ends_with(r#"{"value":0.5}"#));
+    assert!(requests[3].ends_with(r#"{"value":9007199254740993}"#));
+}
+
+#[tokio::test]
+async fn test_with_transaction_commits_or_rolls_back() {
+    let (endpoint, server) = mock_server(vec![
+        (201, r#"{"$id":"tx-1","status":"pending","operations":0}"#),
+        (201, r#"{"$id":"tx-1","status":"pending","operations":1}"#),
+        (201, r#"{"$id":"tx-1","status":"pending","operations":2}"#),
+        (200, r#"{"$id":"tx-1","status":"committed","operations":2}"#),
+        (201, r#"{"$id":"tx-2","status":"pending","operations":0}"#),
+        (200, r#"{"$id":"tx-2","status":"rolledBack","operations":0}"#),
+        (201, r#"{"$id":"tx-3","status":"pending","operations":0}"#),
+        (503, r#"{"message":"Service unavailable","code":503}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let staged = databases
+        .with_transaction(Some(60), |transaction| async move {
+            transaction
+                .create_document("shop", "orders", "order-1", &serde_json::json!({"total": 30}), None)
+                .await?;
+            let staged = transaction.delete_document("shop", "carts", "cart-1").await?;
+            Ok::<_, appwrite::AppwriteError>(staged.operations)
+        })
+        .await
+        .unwrap();
+    assert_eq!(staged, 2);
+
+    let result: Result<(), appwrite::AppwriteError> = databases
+        .with_transaction(None, |_transaction| async move {
+            Err(appwrite::AppwriteError::invalid_parameter("out of stock"))
+        })
+        .await;
+    assert!(matches!(result, Err(appwrite::AppwriteError::InvalidParameter(_))));
+
+    // A failed rollback does not replace the error of the closure
+    let result: Result<(), appwrite::AppwriteError> = databases
+        .with_transaction(None, |_transaction| async move {
+            Err(appwrite::AppwriteError::invalid_parameter("out of stock"))
+        })
+        .await;
+    assert!(matches!(result, Err(appwrite::AppwriteError::InvalidParameter(_))));
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("POST /v1/databases/transactions "));
+    assert!(requests[0].contains(r#""ttl":60"#));
+    assert!(requests[1].starts_with("POST /v1/databases/transactions/tx-1/operations "));
+    assert!(requests[1].contains(r#""action":"create""#));
+    assert!(requests[1].contains(r#""data":{"total":30}"#));
+    assert!(requests[2].contains(r#""action":"delete""#));
+    assert!(!requests[2].contains(r#""data""#));
+    assert!(requests[3].starts_with("PATCH /v1/databases/transactions/tx-1 "));
+    assert!(requests[3].contains(r#""commit":true"#));
+    assert!(requests[5].starts_with("PATCH /v1/databases/transactions/tx-2 "));
+    assert!(requests[5].contains(r#""rollback":true"#));
+}
+
+#[tokio::test]
+async fn test_spatial_attributes_and_index() {
+    let (endpoint, server) = mock_server(vec![
+        (202, r#"{"key":"location","type":"point","status":"processing","default":[2.2945,48.8584]}"#),
+        (202, r#"{"key":"zone","type":"polygon","status":"processing","required":true}"#),
+        (202, r#"{"key":"zone_index","type":"spatial","status":"processing","attributes":["zone"]}"#),
+    ])
+    .await;
+    let client = Client::new().set_endpoint(endpoint).unwrap();
+    let databases = Databases::new(&client);
+
+    let location = databases
+        .create_point_attribute("main", "stores", "location", false, Some(appwrite::Point::new(2.2945, 48.8584)))
+        .await
+        .unwrap();
+    assert_eq!(location.default, Some(appwrite::Point::new(2.2945, 48.8584)));
+
+    let zone = databases
+        .create_polygon_attribute("main", "stores", "zone", true, None)
+        .await
+        .unwrap();
+    assert!(zone.required);
+
+    databases
+        .create_index("main", "stores", "zone_index", appwrite::IndexType::Spatial, vec!["zone".to_string()], None)
+        .await
+        .unwrap();
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("POST /v1/databases/main/collections/stores/attributes/point "));
+    assert!(requests[0].contains(r#""default":[2.2945,48.8584]"#));
+    assert!(requests[1].starts_with("POST /v1/databases/main/collections/stores/attributes/polygon "));
+    assert!(!requests[1].contains("default"));
+    assert!(requests[2].contains(r#""type":"spatial""#));
+}
+
+#[tokio::test]
+async fn test_invalid_ids_are_rejected_before_sending() {
+    let client = Client::new().set_endpoint("http://127.0.0.1:9/v1").unwrap();
+    let databases = Databases::new(&client);
+
+    let result = databases
+        .get_document::<serde_json::Value>("main", "movies", "not a valid id", None)
+        .await;
+    assert!(matches!(result, Err(appwrite::AppwriteError::InvalidParameter(_))));
+
+    let result = Storage::new(&client).get_file("", "file-1").await;
+    assert!(matches!(result, Err(appwrite::AppwriteError::MissingParameter(_))));
+
+    // Only resources being created can ask the server for an ID
+    let result = Storage::new(&client)
+        .get_file("bucket-1", appwrite::ID::SERVER_GENERATED)
+        .await;
+    assert!(matches!(result, Err(appwrite::AppwriteError::InvalidParameter(_))));
+
+    let result = databases
+        .create_document(
+            appwrite::ID::SERVER_GENERATED,
+            "movies",
+            appwrite::ID::SERVER_GENERATED,
+            &serde_json::json!({}),
+            None,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(appwrite::AppwriteError::InvalidParameter(error)) if error.contains("database_id")
+    ));
+}